@@ -35,5 +35,18 @@ fn register_timer(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, register_timer);
+/// Benchmark registering many timers and cancelling them before they fire.
+fn cancel_timers(c: &mut Criterion) {
+    c.bench_function("cancel_timers.(100000 timers)", |b| {
+        b.iter(|| {
+            let timers: Vec<Timer> = (0..100_000).map(|_| make_timer()).collect();
+            drop(black_box(timers));
+
+            // Deregistration is queued, so make the reactor process it.
+            black_box(async_io::reactor_metrics().timers());
+        });
+    });
+}
+
+criterion_group!(benches, register_timer, cancel_timers);
 criterion_main!(benches);
@@ -1,4 +1,7 @@
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(async_io_no_io_safety)");
+    println!("cargo:rustc-check-cfg=cfg(polling_test_poll_backend)");
+
     let cfg = match autocfg::AutoCfg::new() {
         Ok(cfg) => cfg,
         Err(e) => {
@@ -0,0 +1,133 @@
+//! Compatibility with tokio's I/O traits.
+
+use std::io::{self, IoSlice, Read, Write};
+use std::net::Shutdown;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd as AsRawSource, BorrowedFd};
+#[cfg(windows)]
+use std::os::windows::io::{AsRawSocket as AsRawSource, BorrowedSocket};
+
+use futures_io::{AsyncRead, AsyncWrite};
+use futures_lite::ready;
+use socket2::SockRef;
+
+use crate::Async;
+
+impl<T> Async<T> {
+    /// Wraps the I/O handle into an adapter implementing tokio's I/O traits.
+    ///
+    /// This is useful for passing the handle to protocol libraries written against
+    /// [`tokio::io::AsyncRead`] and [`tokio::io::AsyncWrite`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use std::net::TcpStream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let stream = Async::<TcpStream>::connect(([127, 0, 0, 1], 8000)).await?;
+    /// let stream = stream.compat();
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn compat(self) -> Compat<T> {
+        Compat { inner: self }
+    }
+}
+
+/// An adapter implementing tokio's I/O traits for an [`Async`] I/O handle, created by
+/// [`Async::compat()`].
+///
+/// Reads and writes go straight to the underlying handle, without any buffering of their own.
+#[derive(Debug)]
+pub struct Compat<T> {
+    inner: Async<T>,
+}
+
+impl<T> Compat<T> {
+    /// Gets a reference to the inner [`Async`] I/O handle.
+    pub fn get_ref(&self) -> &Async<T> {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner [`Async`] I/O handle.
+    pub fn get_mut(&mut self) -> &mut Async<T> {
+        &mut self.inner
+    }
+
+    /// Unwraps the inner [`Async`] I/O handle.
+    pub fn into_inner(self) -> Async<T> {
+        self.inner
+    }
+}
+
+impl<T: Read> tokio::io::AsyncRead for Compat<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let n = ready!(Pin::new(&mut self.inner).poll_read(cx, buf.initialize_unfilled()))?;
+        buf.advance(n);
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<T: Write + AsRawSource> tokio::io::AsyncWrite for Compat<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(Pin::new(&mut self.inner).poll_close(cx))?;
+        Poll::Ready(shutdown_write(self.inner.get_ref()))
+    }
+}
+
+/// Shuts down the write half of a socket, like tokio's own sockets do on shutdown.
+///
+/// Handles that aren't sockets, such as pipes, are only flushed.
+fn shutdown_write(io: &impl AsRawSource) -> io::Result<()> {
+    cfg_if::cfg_if! {
+        if #[cfg(unix)] {
+            // Safety: The handle is borrowed for the duration of this call.
+            let fd = unsafe { BorrowedFd::borrow_raw(io.as_raw_fd()) };
+            let not_socket = rustix::io::Errno::NOTSOCK.raw_os_error();
+            let res = SockRef::from(&fd).shutdown(Shutdown::Write);
+        } else {
+            // Safety: The handle is borrowed for the duration of this call.
+            let socket = unsafe { BorrowedSocket::borrow_raw(io.as_raw_socket()) };
+            // WSAENOTSOCK
+            let not_socket = 10038;
+            let res = SockRef::from(&socket).shutdown(Shutdown::Write);
+        }
+    }
+
+    match res {
+        Err(err) if err.raw_os_error() == Some(not_socket) => Ok(()),
+        res => res,
+    }
+}
@@ -1,20 +1,32 @@
 use std::cell::Cell;
 use std::future::Future;
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::io;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use async_lock::OnceCell;
-use futures_lite::pin;
+use futures_lite::{pin, FutureExt};
 use waker_fn::waker_fn;
 
 use crate::reactor::Reactor;
+use crate::Timer;
 
 /// Number of currently active `block_on()` invocations.
 static BLOCK_ON_COUNT: AtomicUsize = AtomicUsize::new(0);
 
+/// Whether the "async-io" thread has been spawned or disabled.
+static THREAD_STATE: AtomicU8 = AtomicU8::new(THREAD_UNDECIDED);
+
+/// Neither [`disable_driver_thread()`] has been called nor the "async-io" thread spawned yet.
+const THREAD_UNDECIDED: u8 = 0;
+/// The "async-io" thread is never going to be spawned.
+const THREAD_DISABLED: u8 = 1;
+/// The "async-io" thread has been spawned.
+const THREAD_SPAWNED: u8 = 2;
+
 /// Unparker for the "async-io" thread.
 fn unparker() -> &'static parking::Unparker {
     static UNPARKER: OnceCell<parking::Unparker> = OnceCell::new();
@@ -22,20 +34,118 @@ fn unparker() -> &'static parking::Unparker {
     UNPARKER.get_or_init_blocking(|| {
         let (parker, unparker) = parking::pair();
 
-        // Spawn a helper thread driving the reactor.
-        //
-        // Note that this thread is not exactly necessary, it's only here to help push things
-        // forward if there are no `Parker`s around or if `Parker`s are just idling and never
-        // parking.
-        thread::Builder::new()
-            .name("async-io".to_string())
-            .spawn(move || main_loop(parker))
-            .expect("cannot spawn async-io thread");
+        let state = THREAD_STATE
+            .compare_exchange(
+                THREAD_UNDECIDED,
+                THREAD_SPAWNED,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            )
+            .unwrap_or_else(|state| state);
+
+        if state == THREAD_DISABLED {
+            // Nobody is listening, so unparking just becomes a no-op.
+            tracing::debug!("the async-io thread is disabled");
+        } else {
+            // Spawn a helper thread driving the reactor.
+            //
+            // Note that this thread is not exactly necessary, it's only here to help push things
+            // forward if there are no `Parker`s around or if `Parker`s are just idling and never
+            // parking.
+            thread::Builder::new()
+                .name("async-io".to_string())
+                .spawn(move || main_loop(parker))
+                .expect("cannot spawn async-io thread");
+        }
 
         unparker
     })
 }
 
+/// Prevents the "async-io" thread from being spawned.
+///
+/// By default, a helper thread named "async-io" is spawned the first time the reactor is used.
+/// It processes I/O events and timers whenever no [`block_on()`] call is doing so. Applications
+/// that run their own executor threads inside [`block_on()`] may opt out of it by calling this
+/// function before the first [`Async`][`crate::Async`] or [`Timer`] is created.
+///
+/// Without the helper thread, I/O events and timers are only processed while some thread is
+/// inside [`block_on()`]. Futures polled by other means are not woken until that happens.
+///
+/// Returns `false` if the thread has already been spawned, in which case this function has no
+/// effect.
+///
+/// # Examples
+///
+/// ```
+/// use async_io::Timer;
+/// use std::time::Duration;
+///
+/// async_io::disable_driver_thread();
+///
+/// // The timer is processed by this thread instead.
+/// async_io::block_on(async {
+///     Timer::after(Duration::from_millis(1)).await;
+/// });
+/// ```
+pub fn disable_driver_thread() -> bool {
+    THREAD_STATE
+        .compare_exchange(
+            THREAD_UNDECIDED,
+            THREAD_DISABLED,
+            Ordering::SeqCst,
+            Ordering::SeqCst,
+        )
+        .unwrap_or_else(|state| state)
+        != THREAD_SPAWNED
+}
+
+/// Processes I/O events and timers on the current thread.
+///
+/// Waits until an I/O event arrives, a timer fires or `timeout` elapses, then wakes the tasks
+/// waiting on them and returns. With a timeout of `None` this waits indefinitely, while a zero
+/// timeout only processes events that are already pending.
+///
+/// This is meant for executors that drive the reactor themselves instead of using
+/// [`block_on()`], usually together with [`disable_driver_thread()`]. Exactly one thread should
+/// drive the reactor this way. If another thread is already processing events, e.g. inside
+/// [`block_on()`] or in the "async-io" thread, this function waits until it is done.
+///
+/// # Examples
+///
+/// ```
+/// use async_io::Timer;
+/// use futures_lite::future;
+/// use std::time::Duration;
+///
+/// async_io::disable_driver_thread();
+///
+/// let mut timer = Timer::after(Duration::from_millis(1));
+/// while future::block_on(future::poll_once(&mut timer)).is_none() {
+///     async_io::poll_io(Some(Duration::from_millis(10))).unwrap();
+/// }
+/// ```
+pub fn poll_io(timeout: Option<Duration>) -> io::Result<()> {
+    let span = tracing::trace_span!("async_io::poll_io");
+    let _enter = span.enter();
+
+    let reactor = Reactor::get();
+    let mut reactor_lock = match reactor.try_lock() {
+        Some(reactor_lock) => reactor_lock,
+        None => {
+            // Interrupt the thread that is waiting on the reactor so that it lets go of the lock.
+            reactor.notify();
+            reactor.lock()
+        }
+    };
+    reactor_lock.react(timeout)
+}
+
+/// Returns `true` if the "async-io" thread has been disabled.
+pub(crate) fn driver_thread_disabled() -> bool {
+    THREAD_STATE.load(Ordering::SeqCst) == THREAD_DISABLED
+}
+
 /// Initializes the "async-io" thread.
 pub(crate) fn init() {
     let _ = unparker();
@@ -95,6 +205,11 @@ fn main_loop(parker: parking::Parker) {
 
 /// Blocks the current thread on a future, processing I/O events when idle.
 ///
+/// Calls to this function may be nested, e.g. from a synchronous callback invoked by a future
+/// that is itself running inside `block_on()`. Every call uses its own parker and the reactor is
+/// never locked while the future is being polled, so the inner call can drive both timers and
+/// I/O. The outer future simply stays blocked until the inner call returns.
+///
 /// # Examples
 ///
 /// ```
@@ -127,7 +242,7 @@ pub fn block_on<T>(future: impl Future<Output = T>) -> T {
 
     thread_local! {
         // Indicates that the current thread is polling I/O, but not necessarily blocked on it.
-        static IO_POLLING: Cell<bool> = Cell::new(false);
+        static IO_POLLING: Cell<bool> = const { Cell::new(false) };
     }
 
     // Prepare the waker.
@@ -201,8 +316,9 @@ pub fn block_on<T>(future: impl Future<Output = T>) -> T {
                     break;
                 }
 
-                // Check if this thread been handling I/O events for a long time.
-                if start.elapsed() > Duration::from_micros(500) {
+                // Check if this thread been handling I/O events for a long time. Without the
+                // "async-io" thread, nobody else might take over, so keep driving the reactor.
+                if start.elapsed() > Duration::from_micros(500) && !driver_thread_disabled() {
                     tracing::trace!("stops hogging the reactor");
 
                     // This thread is clearly processing I/O events for some other threads
@@ -228,6 +344,27 @@ pub fn block_on<T>(future: impl Future<Output = T>) -> T {
     }
 }
 
+/// Blocks the current thread on a future, giving up once `timeout` has elapsed.
+///
+/// Returns `None` if the future did not complete in time, in which case it is dropped before
+/// this function returns. I/O events are processed while waiting, just like in [`block_on()`].
+///
+/// # Examples
+///
+/// ```
+/// use futures_lite::future;
+/// use std::time::Duration;
+///
+/// let res = async_io::block_on_timeout(future::pending::<()>(), Duration::from_millis(10));
+/// assert_eq!(res, None);
+/// ```
+pub fn block_on_timeout<T>(future: impl Future<Output = T>, timeout: Duration) -> Option<T> {
+    block_on(async { Some(future.await) }.or(async {
+        Timer::after(timeout).await;
+        None
+    }))
+}
+
 /// Runs a closure when dropped.
 struct CallOnDrop<F: Fn()>(F);
 
@@ -56,9 +56,12 @@
 #![warn(missing_docs, missing_debug_implementations, rust_2018_idioms)]
 
 use std::convert::TryFrom;
+use std::fmt;
 use std::future::Future;
 use std::io::{self, IoSlice, IoSliceMut, Read, Write};
-use std::net::{SocketAddr, TcpListener, TcpStream, UdpSocket};
+#[cfg(feature = "socket2")]
+use std::mem::MaybeUninit;
+use std::net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll, Waker};
@@ -68,30 +71,38 @@ use std::time::{Duration, Instant};
 use std::os::unix::io::{AsFd, BorrowedFd, OwnedFd};
 #[cfg(unix)]
 use std::{
-    os::unix::io::{AsRawFd, RawFd},
+    os::unix::io::{AsRawFd, IntoRawFd, RawFd},
     os::unix::net::{SocketAddr as UnixSocketAddr, UnixDatagram, UnixListener, UnixStream},
     path::Path,
 };
 
 #[cfg(windows)]
-use std::os::windows::io::{AsRawSocket, RawSocket};
+use std::os::windows::io::{AsRawSocket, IntoRawSocket, RawSocket};
 #[cfg(all(not(async_io_no_io_safety), windows))]
 use std::os::windows::io::{AsSocket, BorrowedSocket, OwnedSocket};
 
 use futures_io::{AsyncRead, AsyncWrite};
 use futures_lite::stream::{self, Stream};
 use futures_lite::{future, pin, ready};
-use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+#[cfg(feature = "socket2")]
+use socket2::TcpKeepalive;
+use socket2::{Domain, Protocol, SockAddr, SockRef, Socket, Type};
 
 use crate::reactor::{Reactor, Source};
 
+#[cfg(feature = "tokio-io")]
+mod compat;
 mod driver;
 mod reactor;
 
 pub mod os;
 
-pub use driver::block_on;
-pub use reactor::{Readable, ReadableOwned, Writable, WritableOwned};
+#[cfg(feature = "tokio-io")]
+pub use compat::Compat;
+pub use driver::{block_on, block_on_timeout, disable_driver_thread, poll_io};
+pub use reactor::{
+    reactor_metrics, ReactorMetrics, Readable, ReadableOwned, Writable, WritableOwned,
+};
 
 /// A future or stream that emits timed events.
 ///
@@ -106,6 +117,14 @@ pub use reactor::{Readable, ReadableOwned, Writable, WritableOwned};
 /// about 16 milliseconds. Because of this limit, the timer may sleep for longer than the
 /// requested duration. It will never sleep for less.
 ///
+/// # Clock
+///
+/// Deadlines are [`Instant`]s, and the reactor only ever waits for the difference between the
+/// next deadline and [`Instant::now()`]. Both come from the platform's monotonic clock, and the
+/// polling backends pass relative timeouts to the OS, so changes to the system time, whether by
+/// NTP or by hand, never make a timer fire early or late. Note that on some platforms the
+/// monotonic clock does not advance while the system is suspended.
+///
 /// # Examples
 ///
 /// Sleep for 1 second:
@@ -151,6 +170,14 @@ pub struct Timer {
 
     /// The period.
     period: Duration,
+
+    /// The granularity to which deadlines are rounded up before registering them in the reactor.
+    ///
+    /// A zero granularity means deadlines are registered as they are.
+    granularity: Duration,
+
+    /// How the next deadline of an interval is chosen after ticks were missed.
+    missed_ticks: MissedTicks,
 }
 
 impl Timer {
@@ -191,6 +218,8 @@ impl Timer {
             id_and_waker: None,
             when: None,
             period: Duration::MAX,
+            granularity: Duration::from_secs(0),
+            missed_ticks: MissedTicks::Skip,
         }
     }
 
@@ -214,6 +243,8 @@ impl Timer {
 
     /// Creates a timer that emits an event once at the given time instant.
     ///
+    /// If `instant` is already in the past, the timer fires the first time it is polled.
+    ///
     /// # Examples
     ///
     /// ```
@@ -232,6 +263,15 @@ impl Timer {
 
     /// Creates a timer that emits events periodically.
     ///
+    /// The first event is emitted after `period`, and the following ones are scheduled relative
+    /// to the previous deadline rather than to the moment the timer was polled, so the ticks do
+    /// not drift over time.
+    ///
+    /// Each event yields the [`Instant`] at which the tick was scheduled. If the timer is polled
+    /// late and one or more ticks were missed, the overdue tick is emitted immediately and the
+    /// missed ones are skipped, so the timer resumes its original cadence. This can be changed
+    /// with [`with_missed_ticks()`][`Timer::with_missed_ticks()`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -252,6 +292,8 @@ impl Timer {
 
     /// Creates a timer that emits events periodically, starting at `start`.
     ///
+    /// Missed ticks are handled the same way as in [`interval()`][`Timer::interval()`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -270,7 +312,64 @@ impl Timer {
             id_and_waker: None,
             when: Some(start),
             period,
+            granularity: Duration::from_secs(0),
+            missed_ticks: MissedTicks::Skip,
+        }
+    }
+
+    /// Lets the reactor fire this timer up to `granularity` late in order to save wakeups.
+    ///
+    /// Deadlines are rounded up to buckets of `granularity` that are shared by all timers with the
+    /// same granularity, so timers whose deadlines fall into the same bucket fire together with a
+    /// single wakeup. A coalesced timer never fires before its deadline. For interval timers,
+    /// rounding is applied to each tick separately, so the ticks don't drift.
+    ///
+    /// Timers are created with a zero granularity, which means they fire as close to their
+    /// deadlines as the platform allows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Timer;
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// Timer::after(Duration::from_millis(10))
+    ///     .with_granularity(Duration::from_millis(5))
+    ///     .await;
+    /// # });
+    /// ```
+    pub fn with_granularity(mut self, granularity: Duration) -> Timer {
+        self.clear();
+        self.granularity = granularity;
+
+        if let (Some(when), Some((id, waker))) = (self.when, self.id_and_waker.as_mut()) {
+            // Re-register the timer with the rounded deadline.
+            *id = Reactor::get().insert_timer(coalesce(when, granularity), waker);
         }
+        self
+    }
+
+    /// Sets how this timer catches up after it was polled too late to emit some of its ticks.
+    ///
+    /// This only matters for interval timers. The default is [`MissedTicks::Skip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::{MissedTicks, Timer};
+    /// use futures_lite::StreamExt;
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let mut refill = Timer::interval(Duration::from_millis(10))
+    ///     .with_missed_ticks(MissedTicks::Burst);
+    /// refill.next().await;
+    /// # });
+    /// ```
+    pub fn with_missed_ticks(mut self, missed_ticks: MissedTicks) -> Timer {
+        self.missed_ticks = missed_ticks;
+        self
     }
 
     /// Indicates whether or not this timer will ever fire.
@@ -321,6 +420,9 @@ impl Timer {
     /// [`set_after()`][`Timer::set_after()`] does not remove the waker associated with the task
     /// that is polling the timer.
     ///
+    /// If the timer has already fired but has not been polled since, the old deadline is
+    /// discarded and the timer only fires at the new one.
+    ///
     /// # Examples
     ///
     /// ```
@@ -349,6 +451,9 @@ impl Timer {
     /// [`set_at()`][`Timer::set_at()`] does not remove the waker associated with the task
     /// that is polling the timer.
     ///
+    /// If the timer has already fired but has not been polled since, the old deadline is
+    /// discarded and the timer only fires at the new one.
+    ///
     /// # Examples
     ///
     /// ```
@@ -371,7 +476,7 @@ impl Timer {
 
         if let Some((id, waker)) = self.id_and_waker.as_mut() {
             // Re-register the timer with the new timeout.
-            *id = Reactor::get().insert_timer(instant, waker);
+            *id = Reactor::get().insert_timer(coalesce(instant, self.granularity), waker);
         }
     }
 
@@ -435,7 +540,7 @@ impl Timer {
 
         if let Some((id, waker)) = self.id_and_waker.as_mut() {
             // Re-register the timer with the new timeout.
-            *id = Reactor::get().insert_timer(start, waker);
+            *id = Reactor::get().insert_timer(coalesce(start, self.granularity), waker);
         }
     }
 
@@ -443,7 +548,7 @@ impl Timer {
     fn clear(&mut self) {
         if let (Some(when), Some((id, _))) = (self.when, self.id_and_waker.as_ref()) {
             // Deregister the timer from the reactor.
-            Reactor::get().remove_timer(when, *id);
+            Reactor::get().remove_timer(coalesce(when, self.granularity), *id);
         }
     }
 }
@@ -452,7 +557,7 @@ impl Drop for Timer {
     fn drop(&mut self) {
         if let (Some(when), Some((id, _))) = (self.when, self.id_and_waker.take()) {
             // Deregister the timer from the reactor.
-            Reactor::get().remove_timer(when, id);
+            Reactor::get().remove_timer(coalesce(when, self.granularity), id);
         }
     }
 }
@@ -476,19 +581,32 @@ impl Stream for Timer {
         let this = self.get_mut();
 
         if let Some(ref mut when) = this.when {
+            // The instant at which the reactor wakes this timer up.
+            let deadline = coalesce(*when, this.granularity);
+
             // Check if the timer has already fired.
-            if Instant::now() >= *when {
-                if let Some((id, _)) = this.id_and_waker.take() {
-                    // Deregister the timer from the reactor.
-                    Reactor::get().remove_timer(*when, id);
-                }
+            let now = Instant::now();
+            if now >= deadline {
+                let id = this.id_and_waker.take().map(|(id, _)| id);
                 let result_time = *when;
-                if let Some(next) = (*when).checked_add(this.period) {
+                if let Some(next) = next_tick(*when, this.period, now, this.missed_ticks) {
                     *when = next;
-                    // Register the timer in the reactor.
-                    let id = Reactor::get().insert_timer(next, cx.waker());
+                    let next = coalesce(next, this.granularity);
+                    let id = match id {
+                        // Move the timer to its next tick in the reactor, keeping its ID.
+                        Some(id) => {
+                            Reactor::get().reschedule_timer(deadline, next, id, cx.waker());
+                            id
+                        }
+                        // Register the timer in the reactor.
+                        None => Reactor::get().insert_timer(next, cx.waker()),
+                    };
                     this.id_and_waker = Some((id, cx.waker().clone()));
                 } else {
+                    if let Some(id) = id {
+                        // Deregister the timer from the reactor.
+                        Reactor::get().remove_timer(deadline, id);
+                    }
                     this.when = None;
                 }
                 return Poll::Ready(Some(result_time));
@@ -496,15 +614,15 @@ impl Stream for Timer {
                 match &this.id_and_waker {
                     None => {
                         // Register the timer in the reactor.
-                        let id = Reactor::get().insert_timer(*when, cx.waker());
+                        let id = Reactor::get().insert_timer(deadline, cx.waker());
                         this.id_and_waker = Some((id, cx.waker().clone()));
                     }
                     Some((id, w)) if !w.will_wake(cx.waker()) => {
                         // Deregister the timer from the reactor to remove the old waker.
-                        Reactor::get().remove_timer(*when, *id);
+                        Reactor::get().remove_timer(deadline, *id);
 
                         // Register the timer in the reactor with the new waker.
-                        let id = Reactor::get().insert_timer(*when, cx.waker());
+                        let id = Reactor::get().insert_timer(deadline, cx.waker());
                         this.id_and_waker = Some((id, cx.waker().clone()));
                     }
                     Some(_) => {}
@@ -516,6 +634,72 @@ impl Stream for Timer {
     }
 }
 
+/// How an interval [`Timer`] behaves when it is polled after one or more ticks were due.
+///
+/// Whatever the policy, every tick yields the [`Instant`] it was scheduled at, so consumers can
+/// tell how late they are.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum MissedTicks {
+    /// Emits the overdue tick immediately and skips the other missed ones, so the timer resumes
+    /// its original cadence.
+    ///
+    /// This is the default.
+    #[default]
+    Skip,
+
+    /// Emits all missed ticks back to back until the timer has caught up with its original
+    /// schedule.
+    Burst,
+
+    /// Emits the overdue tick immediately and schedules the following ticks relative to the
+    /// moment it was emitted, shifting the schedule by the delay.
+    Delay,
+}
+
+/// Computes the deadline following the tick scheduled at `when`.
+///
+/// The next deadline is `when + period`, unless that instant has already passed. In that case the
+/// next deadline depends on `missed_ticks`.
+fn next_tick(
+    when: Instant,
+    period: Duration,
+    now: Instant,
+    missed_ticks: MissedTicks,
+) -> Option<Instant> {
+    let next = when.checked_add(period)?;
+
+    if next > now || period == Duration::from_secs(0) {
+        return Some(next);
+    }
+
+    match missed_ticks {
+        MissedTicks::Skip => {
+            // Skip over the ticks that were missed while the timer wasn't being polled.
+            let behind = now.duration_since(next).as_nanos() % period.as_nanos();
+            now.checked_add(period - Duration::from_nanos(behind as u64))
+        }
+        MissedTicks::Burst => Some(next),
+        MissedTicks::Delay => now.checked_add(period),
+    }
+}
+
+/// Rounds `when` up to a multiple of `granularity` since the reactor was created.
+///
+/// A zero granularity leaves the deadline untouched.
+fn coalesce(when: Instant, granularity: Duration) -> Instant {
+    if granularity == Duration::from_secs(0) {
+        return when;
+    }
+
+    let since_epoch = when.saturating_duration_since(Reactor::get().epoch());
+    match since_epoch.as_nanos() % granularity.as_nanos() {
+        0 => when,
+        rem => when
+            .checked_add(granularity - Duration::from_nanos(rem as u64))
+            .unwrap_or(when),
+    }
+}
+
 /// Async adapter for I/O types.
 ///
 /// This type puts an I/O handle into non-blocking mode, registers it in
@@ -628,6 +812,9 @@ impl<T: AsRawFd> Async<T> {
     /// On Unix systems, the handle must implement `AsRawFd`, while on Windows it must implement
     /// `AsRawSocket`.
     ///
+    /// Regular files and directories cannot be polled for readiness, so registering one with
+    /// [epoll] or [kqueue] fails with [`io::ErrorKind::Unsupported`].
+    ///
     /// [epoll]: https://en.wikipedia.org/wiki/Epoll
     /// [kqueue]: https://en.wikipedia.org/wiki/Kqueue
     /// [event ports]: https://illumos.org/man/port_create
@@ -671,8 +858,72 @@ impl<T: AsRawFd> Async<T> {
             }
         }
 
+        Self::new_nonblocking(io)
+    }
+
+    /// Creates an async I/O handle without setting it to non-blocking mode.
+    ///
+    /// This method will register the handle in [epoll]/[kqueue]/[event ports]/[IOCP].
+    ///
+    /// On Unix systems, the handle must implement `AsRawFd`, while on Windows it must implement
+    /// `AsRawSocket`.
+    ///
+    /// The caller should ensure that the handle is already in non-blocking mode. Otherwise, I/O
+    /// operations may block the current thread and cause a deadlock in an asynchronous context.
+    ///
+    /// The handle may also be a bare [`RawFd`] owned by someone else, such as a file descriptor
+    /// handed out by a C library. In that case, the caller is responsible for keeping the file
+    /// descriptor open for as long as the returned [`Async`] exists, and it will not be closed when
+    /// the [`Async`] is dropped.
+    ///
+    /// Regular files and directories cannot be polled for readiness, so registering one with
+    /// [epoll] or [kqueue] fails with [`io::ErrorKind::Unsupported`]. Use a thread pool like
+    /// [`blocking`] for file I/O instead.
+    ///
+    /// [`blocking`]: https://docs.rs/blocking
+    /// [epoll]: https://en.wikipedia.org/wiki/Epoll
+    /// [kqueue]: https://en.wikipedia.org/wiki/Kqueue
+    /// [event ports]: https://illumos.org/man/port_create
+    /// [IOCP]: https://learn.microsoft.com/en-us/windows/win32/fileio/i-o-completion-ports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::{SocketAddr, TcpListener};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+    /// listener.set_nonblocking(true)?;
+    /// let listener = Async::new_nonblocking(listener)?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn new_nonblocking(io: T) -> io::Result<Async<T>> {
+        // kqueue accepts regular files and reports them as always ready, so refuse them up front.
+        #[cfg(any(
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd",
+            target_os = "dragonfly",
+        ))]
+        check_pollable(io.as_raw_fd())?;
+
+        let source = Reactor::get().insert_io(io.as_raw_fd()).map_err(|err| {
+            // epoll rejects regular files and directories with a cryptic `EPERM`, so only look at
+            // the file type once registration has failed.
+            if err.raw_os_error() == Some(rustix::io::Errno::PERM.raw_os_error()) {
+                check_pollable(io.as_raw_fd()).err().unwrap_or(err)
+            } else {
+                err
+            }
+        })?;
+
         Ok(Async {
-            source: Reactor::get().insert_io(raw)?,
+            source,
             io: Some(io),
         })
     }
@@ -685,6 +936,16 @@ impl<T: AsRawFd> AsRawFd for Async<T> {
     }
 }
 
+#[cfg(unix)]
+impl<T: IntoRawFd> IntoRawFd for Async<T> {
+    fn into_raw_fd(mut self) -> RawFd {
+        let io = self.io.take().unwrap();
+        // Deregister and ignore errors because this conversion cannot fail.
+        Reactor::get().remove_io(&self.source).ok();
+        io.into_raw_fd()
+    }
+}
+
 #[cfg(all(not(async_io_no_io_safety), unix))]
 impl<T: AsFd> AsFd for Async<T> {
     fn as_fd(&self) -> BorrowedFd<'_> {
@@ -747,8 +1008,44 @@ impl<T: AsRawSocket> Async<T> {
         // `TimerFd` implements it, we can remove this unsafe and simplify this.
         rustix::io::ioctl_fionbio(borrowed, true)?;
 
+        Self::new_nonblocking(io)
+    }
+
+    /// Creates an async I/O handle without setting it to non-blocking mode.
+    ///
+    /// This method will register the handle in [epoll]/[kqueue]/[event ports]/[IOCP].
+    ///
+    /// On Unix systems, the handle must implement `AsRawFd`, while on Windows it must implement
+    /// `AsRawSocket`.
+    ///
+    /// The caller should ensure that the handle is already in non-blocking mode. Otherwise, I/O
+    /// operations may block the current thread and cause a deadlock in an asynchronous context.
+    ///
+    /// A socket owned by someone else, such as one handed out by a C library, can be registered
+    /// by wrapping its [`RawSocket`] in a type that implements `AsRawSocket` and doesn't close it
+    /// on drop, since [`RawSocket`] itself doesn't implement that trait. In that case, the caller
+    /// is responsible for keeping the socket open for as long as the returned [`Async`] exists.
+    ///
+    /// [epoll]: https://en.wikipedia.org/wiki/Epoll
+    /// [kqueue]: https://en.wikipedia.org/wiki/Kqueue
+    /// [event ports]: https://illumos.org/man/port_create
+    /// [IOCP]: https://learn.microsoft.com/en-us/windows/win32/fileio/i-o-completion-ports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::{SocketAddr, TcpListener};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))?;
+    /// listener.set_nonblocking(true)?;
+    /// let listener = Async::new_nonblocking(listener)?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn new_nonblocking(io: T) -> io::Result<Async<T>> {
         Ok(Async {
-            source: Reactor::get().insert_io(sock)?,
+            source: Reactor::get().insert_io(io.as_raw_socket())?,
             io: Some(io),
         })
     }
@@ -761,6 +1058,16 @@ impl<T: AsRawSocket> AsRawSocket for Async<T> {
     }
 }
 
+#[cfg(windows)]
+impl<T: IntoRawSocket> IntoRawSocket for Async<T> {
+    fn into_raw_socket(mut self) -> RawSocket {
+        let io = self.io.take().unwrap();
+        // Deregister and ignore errors because this conversion cannot fail.
+        Reactor::get().remove_io(&self.source).ok();
+        io.into_raw_socket()
+    }
+}
+
 #[cfg(all(not(async_io_no_io_safety), windows))]
 impl<T: AsSocket> AsSocket for Async<T> {
     fn as_socket(&self) -> BorrowedSocket<'_> {
@@ -806,6 +1113,14 @@ impl<T> Async<T> {
 
     /// Gets a mutable reference to the inner I/O handle.
     ///
+    /// The handle stays registered in the reactor. It must not be put back into blocking mode,
+    /// closed, or replaced with a different file descriptor or socket through this reference,
+    /// or the reactor will keep waiting on the old one.
+    ///
+    /// To perform I/O through the mutable reference, use
+    /// [`read_with_mut()`][`Async::read_with_mut()`] and
+    /// [`write_with_mut()`][`Async::write_with_mut()`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -825,6 +1140,9 @@ impl<T> Async<T> {
     ///
     /// This method will **not** put the I/O handle back into blocking mode.
     ///
+    /// Standard library sockets can also be unwrapped with [`TryFrom`], which does put them back
+    /// into blocking mode.
+    ///
     /// # Examples
     ///
     /// ```
@@ -849,6 +1167,13 @@ impl<T> Async<T> {
     ///
     /// This method completes when a read operation on this I/O handle wouldn't block.
     ///
+    /// Readiness is only a hint: the inner I/O handle is not touched, and a subsequent read may
+    /// still fail with [`io::ErrorKind::WouldBlock`] (for example, if another task got to it
+    /// first). In that case, simply wait again.
+    ///
+    /// Any number of tasks may wait concurrently, and all of them are woken by the same event.
+    /// With many waiters, most of them are then likely to find nothing to read.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -869,6 +1194,24 @@ impl<T> Async<T> {
     /// Waits until the I/O handle is readable.
     ///
     /// This method completes when a read operation on this I/O handle wouldn't block.
+    ///
+    /// Unlike [`readable()`][`Async::readable()`], the returned future holds an [`Arc`] to the
+    /// I/O handle rather than borrowing it, so it can be stored or spawned as a `'static` task.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use std::net::TcpListener;
+    /// use std::sync::Arc;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let listener = Arc::new(Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?);
+    ///
+    /// // Wait until a client can be accepted.
+    /// listener.readable_owned().await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
     pub fn readable_owned(self: Arc<Self>) -> ReadableOwned<T> {
         Source::readable_owned(self)
     }
@@ -877,6 +1220,13 @@ impl<T> Async<T> {
     ///
     /// This method completes when a write operation on this I/O handle wouldn't block.
     ///
+    /// Readiness is only a hint: the inner I/O handle is not touched, and a subsequent write may
+    /// still fail with [`io::ErrorKind::WouldBlock`] (for example, if another task got to it
+    /// first). In that case, simply wait again.
+    ///
+    /// Any number of tasks may wait concurrently, and all of them are woken by the same event.
+    /// With many waiters, most of them are then likely to find nothing to write.
+    ///
     /// # Examples
     ///
     /// ```
@@ -898,6 +1248,25 @@ impl<T> Async<T> {
     /// Waits until the I/O handle is writable.
     ///
     /// This method completes when a write operation on this I/O handle wouldn't block.
+    ///
+    /// Unlike [`writable()`][`Async::writable()`], the returned future holds an [`Arc`] to the
+    /// I/O handle rather than borrowing it, so it can be stored or spawned as a `'static` task.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::{TcpStream, ToSocketAddrs};
+    /// use std::sync::Arc;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let addr = "example.com:80".to_socket_addrs()?.next().unwrap();
+    /// let stream = Arc::new(Async::<TcpStream>::connect(addr).await?);
+    ///
+    /// // Wait until the stream is writable.
+    /// stream.writable_owned().await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
     pub fn writable_owned(self: Arc<Self>) -> WritableOwned<T> {
         Source::writable_owned(self)
     }
@@ -974,6 +1343,10 @@ impl<T> Async<T> {
     ///
     /// The closure receives a shared reference to the I/O handle.
     ///
+    /// The returned future is cancellation safe: `op` only runs while the future is being polled,
+    /// and the future completes as soon as `op` succeeds. Dropping it early therefore never
+    /// loses data that has already been read.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1043,6 +1416,10 @@ impl<T> Async<T> {
     ///
     /// The closure receives a shared reference to the I/O handle.
     ///
+    /// The returned future is cancellation safe: `op` only runs while the future is being polled,
+    /// and the future completes as soon as `op` succeeds. Dropping it early therefore never
+    /// loses data that has already been written.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1104,6 +1481,34 @@ impl<T> Async<T> {
             optimistic(self.writable()).await?;
         }
     }
+
+    /// Splits the I/O handle into owned read and write halves.
+    ///
+    /// The halves share the I/O handle through an [`Arc`], so each of them can be moved into a
+    /// different task. The read half only ever waits for readability and the write half only
+    /// for writability. The I/O handle is deregistered and closed once both halves are dropped,
+    /// and [`ReadHalf::reunite()`] puts them back together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use futures_lite::future;
+    /// use std::net::{TcpListener, TcpStream};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+    /// let addr = listener.get_ref().local_addr()?;
+    /// let stream = Async::<TcpStream>::connect(addr).await?;
+    ///
+    /// let (reader, writer) = stream.split();
+    /// let stream = reader.reunite(writer).unwrap();
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn split(self) -> (ReadHalf<T>, WriteHalf<T>) {
+        let io = Arc::new(self);
+        (ReadHalf(io.clone()), WriteHalf(io))
+    }
 }
 
 impl<T> AsRef<T> for Async<T> {
@@ -1284,11 +1689,118 @@ where
     }
 }
 
+/// The read half of an [`Async`] I/O handle, created by [`Async::split()`].
+#[derive(Debug)]
+pub struct ReadHalf<T>(Arc<Async<T>>);
+
+impl<T> ReadHalf<T> {
+    /// Gets a reference to the inner I/O handle.
+    pub fn get_ref(&self) -> &T {
+        self.0.get_ref()
+    }
+
+    /// Puts the two halves back together into the original I/O handle.
+    ///
+    /// Returns both halves inside a [`ReuniteError`] if they were split from different handles.
+    pub fn reunite(self, other: WriteHalf<T>) -> Result<Async<T>, ReuniteError<T>> {
+        if !Arc::ptr_eq(&self.0, &other.0) {
+            return Err(ReuniteError(self, other));
+        }
+        drop(other);
+        Ok(Arc::try_unwrap(self.0).unwrap_or_else(|_| unreachable!("halves cannot be cloned")))
+    }
+}
+
+impl<T> AsyncRead for ReadHalf<T>
+where
+    for<'a> &'a T: Read,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.0).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [IoSliceMut<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.0).poll_read_vectored(cx, bufs)
+    }
+}
+
+/// The write half of an [`Async`] I/O handle, created by [`Async::split()`].
+#[derive(Debug)]
+pub struct WriteHalf<T>(Arc<Async<T>>);
+
+impl<T> WriteHalf<T> {
+    /// Gets a reference to the inner I/O handle.
+    pub fn get_ref(&self) -> &T {
+        self.0.get_ref()
+    }
+
+    /// Puts the two halves back together into the original I/O handle.
+    ///
+    /// Returns both halves inside a [`ReuniteError`] if they were split from different handles.
+    pub fn reunite(self, other: ReadHalf<T>) -> Result<Async<T>, ReuniteError<T>> {
+        other.reunite(self)
+    }
+}
+
+impl<T> AsyncWrite for WriteHalf<T>
+where
+    for<'a> &'a T: Write,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.0).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut &*self.0).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.0).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut &*self.0).poll_close(cx)
+    }
+}
+
+/// Error returned by [`ReadHalf::reunite()`] when the halves come from different I/O handles.
+///
+/// The two halves that were passed in can be taken back out of it.
+#[derive(Debug)]
+pub struct ReuniteError<T>(pub ReadHalf<T>, pub WriteHalf<T>);
+
+impl<T> fmt::Display for ReuniteError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tried to reunite halves that are not from the same I/O handle")
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for ReuniteError<T> {}
+
 impl Async<TcpListener> {
     /// Creates a TCP listener bound to the specified address.
     ///
     /// Binding with port number 0 will request an available port from the OS.
     ///
+    /// To share the address with other listeners, use
+    /// [`bind_reuseport()`][`Async::<TcpListener>::bind_reuseport()`] instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -1305,14 +1817,50 @@ impl Async<TcpListener> {
         Async::new(TcpListener::bind(addr)?)
     }
 
-    /// Accepts a new incoming TCP connection.
+    /// Creates a TCP listener bound to the specified address with `SO_REUSEADDR` and
+    /// `SO_REUSEPORT` set.
     ///
-    /// When a connection is established, it will be returned as a TCP stream together with its
-    /// remote address.
+    /// Several listeners, possibly in different processes, can be bound to the same address this
+    /// way, and the kernel distributes incoming connections between them. This is useful for
+    /// restarting a server without downtime or for sharding connections across processes.
+    ///
+    /// On illumos and Solaris, which don't support `SO_REUSEPORT`, only `SO_REUSEADDR` is set.
+    /// On Windows, neither option is set because `SO_REUSEADDR` there lets other sockets steal
+    /// the address, so this method behaves like [`bind()`][`Async::<TcpListener>::bind()`].
     ///
     /// # Examples
     ///
-    /// ```no_run
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::TcpListener;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let listener = Async::<TcpListener>::bind_reuseport(([127, 0, 0, 1], 0))?;
+    /// println!("Listening on {}", listener.get_ref().local_addr()?);
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn bind_reuseport<A: Into<SocketAddr>>(addr: A) -> io::Result<Async<TcpListener>> {
+        let addr = addr.into();
+        let socket = Socket::new(Domain::for_address(addr), Type::STREAM, Some(Protocol::TCP))?;
+
+        #[cfg(unix)]
+        socket.set_reuse_address(true)?;
+        #[cfg(all(unix, not(any(target_os = "illumos", target_os = "solaris"))))]
+        socket.set_reuse_port(true)?;
+
+        socket.bind(&addr.into())?;
+        socket.listen(128)?;
+        Async::new(TcpListener::from(socket))
+    }
+
+    /// Accepts a new incoming TCP connection.
+    ///
+    /// When a connection is established, it will be returned as a TCP stream together with its
+    /// remote address.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
     /// use async_io::Async;
     /// use std::net::TcpListener;
     ///
@@ -1329,19 +1877,24 @@ impl Async<TcpListener> {
 
     /// Returns a stream of incoming TCP connections.
     ///
-    /// The stream is infinite, i.e. it never stops with a [`None`].
+    /// The stream is infinite, i.e. it never stops with a [`None`]. Errors, such as a
+    /// connection being aborted before it was accepted or the process running out of file
+    /// descriptors, are yielded as items and do not end the stream. After running out of file
+    /// descriptors, the stream waits a little before accepting again, so that a loop that keeps
+    /// polling it doesn't spin on the same error.
+    ///
+    /// The returned stream is [`Unpin`], so it doesn't need to be pinned before use.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use async_io::Async;
-    /// use futures_lite::{pin, stream::StreamExt};
+    /// use futures_lite::stream::StreamExt;
     /// use std::net::TcpListener;
     ///
     /// # futures_lite::future::block_on(async {
     /// let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 8000))?;
-    /// let incoming = listener.incoming();
-    /// pin!(incoming);
+    /// let mut incoming = listener.incoming();
     ///
     /// while let Some(stream) = incoming.next().await {
     ///     let stream = stream?;
@@ -1349,10 +1902,33 @@ impl Async<TcpListener> {
     /// }
     /// # std::io::Result::Ok(()) });
     /// ```
-    pub fn incoming(&self) -> impl Stream<Item = io::Result<Async<TcpStream>>> + Send + '_ {
-        stream::unfold(self, |listener| async move {
-            let res = listener.accept().await.map(|(stream, _)| stream);
-            Some((res, listener))
+    pub fn incoming(&self) -> impl Stream<Item = io::Result<Async<TcpStream>>> + Send + Unpin + '_ {
+        let mut backoff: Option<Timer> = None;
+        // Each stream waits with its own future, so that concurrent streams on the same listener
+        // don't keep replacing and waking each other's wakers.
+        let mut readable: Option<Readable<'_, _>> = None;
+
+        stream::poll_fn(move |cx| loop {
+            if let Some(timer) = backoff.as_mut() {
+                ready!(Pin::new(timer).poll(cx));
+                backoff = None;
+            }
+
+            match self.get_ref().accept() {
+                Ok((stream, _)) => return Poll::Ready(Some(Async::new(stream))),
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                Err(err) => {
+                    if out_of_fds(&err) {
+                        backoff = Some(Timer::after(ACCEPT_BACKOFF));
+                    }
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+            let res = ready!(Pin::new(readable.get_or_insert_with(|| self.readable())).poll(cx));
+            readable = None;
+            if let Err(err) = res {
+                return Poll::Ready(Some(Err(err)));
+            }
         })
     }
 }
@@ -1365,6 +1941,16 @@ impl TryFrom<std::net::TcpListener> for Async<std::net::TcpListener> {
     }
 }
 
+impl TryFrom<Async<std::net::TcpListener>> for std::net::TcpListener {
+    type Error = io::Error;
+
+    fn try_from(listener: Async<std::net::TcpListener>) -> io::Result<Self> {
+        let listener = listener.into_inner()?;
+        listener.set_nonblocking(false)?;
+        Ok(listener)
+    }
+}
+
 impl Async<TcpStream> {
     /// Creates a TCP connection to the specified address.
     ///
@@ -1383,8 +1969,61 @@ impl Async<TcpStream> {
         // Begin async connect.
         let addr = addr.into();
         let domain = Domain::for_address(addr);
-        let socket = connect(addr.into(), domain, Some(Protocol::TCP))?;
-        let stream = Async::new(TcpStream::from(socket))?;
+        let socket = connect(None, addr.into(), domain, Some(Protocol::TCP))?;
+        Self::finish_connect(socket).await
+    }
+
+    /// Creates a TCP connection to the specified address from the specified local address.
+    ///
+    /// The socket is bound to `local` before connecting, which selects the source address on
+    /// hosts with multiple interfaces. Binding with port number 0 will request an available
+    /// port from the OS.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidInput`] if `local` and `remote` belong
+    /// to different address families.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let remote = "example.com:80".to_socket_addrs()?.next().unwrap();
+    /// let local = match remote {
+    ///     SocketAddr::V4(_) => SocketAddr::from(([0, 0, 0, 0], 0)),
+    ///     SocketAddr::V6(_) => SocketAddr::from(([0; 16], 0)),
+    /// };
+    /// let stream = Async::<TcpStream>::connect_from(local, remote).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn connect_from<L: Into<SocketAddr>, R: Into<SocketAddr>>(
+        local: L,
+        remote: R,
+    ) -> io::Result<Async<TcpStream>> {
+        let (local, remote) = (local.into(), remote.into());
+        if local.is_ipv4() != remote.is_ipv4() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "local and remote addresses belong to different address families",
+            ));
+        }
+
+        // Begin async connect.
+        let domain = Domain::for_address(remote);
+        let socket = connect(
+            Some(local.into()),
+            remote.into(),
+            domain,
+            Some(Protocol::TCP),
+        )?;
+        Self::finish_connect(socket).await
+    }
+
+    /// Waits for a connect started on `socket` to complete.
+    async fn finish_connect(socket: Socket) -> io::Result<Async<TcpStream>> {
+        // The socket was created in non-blocking mode by `connect()`.
+        let stream = Async::new_nonblocking(TcpStream::from(socket))?;
 
         // The stream becomes writable when connected.
         stream.writable().await?;
@@ -1396,9 +2035,97 @@ impl Async<TcpStream> {
         }
     }
 
+    /// Creates a TCP connection to the first reachable address in `addrs`.
+    ///
+    /// Connection attempts are raced in the style of "Happy Eyeballs" (RFC 8305): addresses are
+    /// reordered so that IPv6 and IPv4 alternate, starting with the family of the first address,
+    /// and a new attempt is started every 250 milliseconds, or right away if the previous one
+    /// fails. The first attempt to succeed is returned and all others are dropped.
+    ///
+    /// If every attempt fails, the error of the last one to fail is returned. An empty list of
+    /// addresses results in an error of kind [`io::ErrorKind::InvalidInput`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::{TcpStream, ToSocketAddrs};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let addrs = "example.com:80".to_socket_addrs()?;
+    /// let stream = Async::<TcpStream>::connect_addrs(addrs).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn connect_addrs<I: IntoIterator<Item = SocketAddr>>(
+        addrs: I,
+    ) -> io::Result<Async<TcpStream>> {
+        const ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+        // Interleave address families, starting with the family of the first address.
+        let (mut preferred, mut other) = (Vec::<SocketAddr>::new(), Vec::new());
+        for addr in addrs {
+            match preferred.first() {
+                Some(first) if first.is_ipv6() != addr.is_ipv6() => other.push(addr),
+                _ => preferred.push(addr),
+            }
+        }
+        let mut other = other.into_iter();
+        let mut addrs = preferred
+            .into_iter()
+            .flat_map(|addr| std::iter::once(addr).chain(other.next()))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .chain(other)
+            .peekable();
+
+        type Attempt = Pin<Box<dyn Future<Output = io::Result<Async<TcpStream>>> + Send>>;
+        let mut attempts: Vec<Attempt> = Vec::new();
+        let mut timer = Timer::never();
+        let mut launch = true;
+        let mut last_err = None;
+
+        future::poll_fn(|cx| loop {
+            // Start the next attempt if the previous one failed or has been running for too long.
+            if launch || Pin::new(&mut timer).poll(cx).is_ready() {
+                launch = false;
+                match addrs.next() {
+                    Some(addr) => {
+                        attempts.push(Box::pin(Self::connect(addr)));
+                        timer.set_after(ATTEMPT_DELAY);
+                    }
+                    None => timer = Timer::never(),
+                }
+            }
+
+            let mut i = 0;
+            while i < attempts.len() {
+                match attempts[i].as_mut().poll(cx) {
+                    Poll::Ready(Ok(stream)) => return Poll::Ready(Ok(stream)),
+                    Poll::Ready(Err(err)) => {
+                        last_err = Some(err);
+                        drop(attempts.swap_remove(i));
+                        launch = addrs.peek().is_some();
+                    }
+                    Poll::Pending => i += 1,
+                }
+            }
+
+            if attempts.is_empty() && addrs.peek().is_none() {
+                return Poll::Ready(Err(last_err.take().unwrap_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "no addresses to connect to")
+                })));
+            }
+            if !launch {
+                return Poll::Pending;
+            }
+        })
+        .await
+    }
+
     /// Reads data from the stream without removing it from the buffer.
     ///
     /// Returns the number of bytes read. Successive calls of this method read the same data.
+    /// If `buf` is empty, `Ok(0)` is returned immediately without waiting for data.
     ///
     /// # Examples
     ///
@@ -1420,8 +2147,113 @@ impl Async<TcpStream> {
     /// # std::io::Result::Ok(()) });
     /// ```
     pub async fn peek(&self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
         self.read_with(|io| io.peek(buf)).await
     }
+
+    /// Shuts down the read half, the write half, or both halves of the connection.
+    ///
+    /// Shutting down the write half signals end-of-stream to the peer while responses can
+    /// still be read. Any write attempted afterwards fails with an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use futures_lite::io::{AsyncReadExt, AsyncWriteExt};
+    /// use std::net::{Shutdown, TcpStream, ToSocketAddrs};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let addr = "example.com:80".to_socket_addrs()?.next().unwrap();
+    /// let mut stream = Async::<TcpStream>::connect(addr).await?;
+    ///
+    /// stream
+    ///     .write_all(b"GET / HTTP/1.0\r\nHost: example.com\r\n\r\n")
+    ///     .await?;
+    /// stream.shutdown(Shutdown::Write)?;
+    ///
+    /// let mut response = Vec::new();
+    /// stream.read_to_end(&mut response).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn shutdown(&self, how: Shutdown) -> io::Result<()> {
+        self.get_ref().shutdown(how)
+    }
+
+    /// Gets the value of the `TCP_NODELAY` option on this socket.
+    pub fn nodelay(&self) -> io::Result<bool> {
+        self.get_ref().nodelay()
+    }
+
+    /// Sets the value of the `TCP_NODELAY` option on this socket.
+    ///
+    /// When enabled, segments are sent as soon as possible instead of being buffered by Nagle's
+    /// algorithm until there is enough data to send.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use std::net::TcpStream;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let stream = Async::<TcpStream>::connect(([127, 0, 0, 1], 8080)).await?;
+    /// stream.set_nodelay(true)?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn set_nodelay(&self, nodelay: bool) -> io::Result<()> {
+        self.get_ref().set_nodelay(nodelay)
+    }
+
+    /// Gets the value of the `IP_TTL` option on this socket.
+    pub fn ttl(&self) -> io::Result<u32> {
+        self.get_ref().ttl()
+    }
+
+    /// Sets the value of the `IP_TTL` option on this socket.
+    ///
+    /// This is the time-to-live of every IP packet sent from this socket.
+    pub fn set_ttl(&self, ttl: u32) -> io::Result<()> {
+        self.get_ref().set_ttl(ttl)
+    }
+
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    pub fn keepalive(&self) -> io::Result<bool> {
+        SockRef::from(self.get_ref()).keepalive()
+    }
+
+    /// Enables TCP keepalive with the given parameters, or disables it with `None`.
+    ///
+    /// The idle time, probe interval, and retry count are configured with
+    /// [`socket2::TcpKeepalive`], which only offers the ones the platform supports. This method
+    /// is only available with the `socket2` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::TcpKeepalive;
+    /// use std::net::TcpStream;
+    /// use std::time::Duration;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let stream = Async::<TcpStream>::connect(([127, 0, 0, 1], 8080)).await?;
+    /// let keepalive = TcpKeepalive::new()
+    ///     .with_time(Duration::from_secs(60))
+    ///     .with_interval(Duration::from_secs(10));
+    /// stream.set_keepalive(Some(&keepalive))?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    #[cfg(feature = "socket2")]
+    pub fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> io::Result<()> {
+        let socket = SockRef::from(self.get_ref());
+        match keepalive {
+            Some(keepalive) => socket.set_tcp_keepalive(keepalive),
+            None => socket.set_keepalive(false),
+        }
+    }
 }
 
 impl TryFrom<std::net::TcpStream> for Async<std::net::TcpStream> {
@@ -1432,6 +2264,16 @@ impl TryFrom<std::net::TcpStream> for Async<std::net::TcpStream> {
     }
 }
 
+impl TryFrom<Async<std::net::TcpStream>> for std::net::TcpStream {
+    type Error = io::Error;
+
+    fn try_from(stream: Async<std::net::TcpStream>) -> io::Result<Self> {
+        let stream = stream.into_inner()?;
+        stream.set_nonblocking(false)?;
+        Ok(stream)
+    }
+}
+
 impl Async<UdpSocket> {
     /// Creates a UDP socket bound to the specified address.
     ///
@@ -1453,6 +2295,84 @@ impl Async<UdpSocket> {
         Async::new(UdpSocket::bind(addr)?)
     }
 
+    /// Connects the UDP socket to a remote address.
+    ///
+    /// Once connected, [`send()`][`Async::<UdpSocket>::send()`] and
+    /// [`recv()`][`Async::<UdpSocket>::recv()`] exchange datagrams with that address only, and
+    /// ICMP errors from the peer are reported by them. Datagrams from other addresses are
+    /// discarded.
+    ///
+    /// Connecting a UDP socket doesn't involve any I/O, so this method doesn't block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use async_io::Async;
+    /// use std::net::UdpSocket;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+    /// socket.connect(([127, 0, 0, 1], 9000))?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn connect<A: Into<SocketAddr>>(&self, addr: A) -> io::Result<()> {
+        self.get_ref().connect(addr.into())
+    }
+
+    /// Joins an IPv4 multicast group.
+    ///
+    /// `interface` is the address of the local interface to join the group on, or
+    /// [`Ipv4Addr::UNSPECIFIED`] to let the OS choose one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use std::net::{Ipv4Addr, UdpSocket};
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::<UdpSocket>::bind(([0, 0, 0, 0], 5353))?;
+    /// socket.join_multicast_v4(Ipv4Addr::new(224, 0, 0, 251), Ipv4Addr::UNSPECIFIED)?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub fn join_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> io::Result<()> {
+        self.get_ref().join_multicast_v4(&group, &interface)
+    }
+
+    /// Leaves an IPv4 multicast group.
+    ///
+    /// The arguments must match the ones passed to
+    /// [`join_multicast_v4()`][`Async::<UdpSocket>::join_multicast_v4()`].
+    pub fn leave_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> io::Result<()> {
+        self.get_ref().leave_multicast_v4(&group, &interface)
+    }
+
+    /// Joins an IPv6 multicast group.
+    ///
+    /// `interface` is the index of the local interface to join the group on, or 0 to let the OS
+    /// choose one.
+    pub fn join_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> io::Result<()> {
+        self.get_ref().join_multicast_v6(&group, interface)
+    }
+
+    /// Leaves an IPv6 multicast group.
+    ///
+    /// The arguments must match the ones passed to
+    /// [`join_multicast_v6()`][`Async::<UdpSocket>::join_multicast_v6()`].
+    pub fn leave_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> io::Result<()> {
+        self.get_ref().leave_multicast_v6(&group, interface)
+    }
+
+    /// Sets whether IPv4 multicast datagrams sent by this socket are looped back to it.
+    pub fn set_multicast_loop_v4(&self, on: bool) -> io::Result<()> {
+        self.get_ref().set_multicast_loop_v4(on)
+    }
+
+    /// Sets whether IPv6 multicast datagrams sent by this socket are looped back to it.
+    pub fn set_multicast_loop_v6(&self, on: bool) -> io::Result<()> {
+        self.get_ref().set_multicast_loop_v6(on)
+    }
+
     /// Receives a single datagram message.
     ///
     /// Returns the number of bytes read and the address the message came from.
@@ -1531,8 +2451,8 @@ impl Async<UdpSocket> {
     /// This method must be called with a valid byte slice of sufficient size to hold the message.
     /// If the message is too long to fit, excess bytes may get discarded.
     ///
-    /// The [`connect`][`UdpSocket::connect()`] method connects this socket to a remote address.
-    /// This method will fail if the socket is not connected.
+    /// The [`connect`][`Async::<UdpSocket>::connect()`] method connects this socket to a remote
+    /// address. This method will fail if the socket is not connected.
     ///
     /// # Examples
     ///
@@ -1542,7 +2462,7 @@ impl Async<UdpSocket> {
     ///
     /// # futures_lite::future::block_on(async {
     /// let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 8000))?;
-    /// socket.get_ref().connect("127.0.0.1:9000")?;
+    /// socket.connect(([127, 0, 0, 1], 9000))?;
     ///
     /// let mut buf = [0u8; 1024];
     /// let len = socket.recv(&mut buf).await?;
@@ -1560,8 +2480,8 @@ impl Async<UdpSocket> {
     /// This method must be called with a valid byte slice of sufficient size to hold the message.
     /// If the message is too long to fit, excess bytes may get discarded.
     ///
-    /// The [`connect`][`UdpSocket::connect()`] method connects this socket to a remote address.
-    /// This method will fail if the socket is not connected.
+    /// The [`connect`][`Async::<UdpSocket>::connect()`] method connects this socket to a remote
+    /// address. This method will fail if the socket is not connected.
     ///
     /// # Examples
     ///
@@ -1571,7 +2491,7 @@ impl Async<UdpSocket> {
     ///
     /// # futures_lite::future::block_on(async {
     /// let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 8000))?;
-    /// socket.get_ref().connect("127.0.0.1:9000")?;
+    /// socket.connect(([127, 0, 0, 1], 9000))?;
     ///
     /// let mut buf = [0u8; 1024];
     /// let len = socket.peek(&mut buf).await?;
@@ -1585,8 +2505,8 @@ impl Async<UdpSocket> {
     ///
     /// Returns the number of bytes written.
     ///
-    /// The [`connect`][`UdpSocket::connect()`] method connects this socket to a remote address.
-    /// This method will fail if the socket is not connected.
+    /// The [`connect`][`Async::<UdpSocket>::connect()`] method connects this socket to a remote
+    /// address. This method will fail if the socket is not connected.
     ///
     /// # Examples
     ///
@@ -1596,7 +2516,7 @@ impl Async<UdpSocket> {
     ///
     /// # futures_lite::future::block_on(async {
     /// let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 8000))?;
-    /// socket.get_ref().connect("127.0.0.1:9000")?;
+    /// socket.connect(([127, 0, 0, 1], 9000))?;
     ///
     /// let msg = b"hello";
     /// let len = socket.send(msg).await?;
@@ -1615,6 +2535,182 @@ impl TryFrom<std::net::UdpSocket> for Async<std::net::UdpSocket> {
     }
 }
 
+impl TryFrom<Async<std::net::UdpSocket>> for std::net::UdpSocket {
+    type Error = io::Error;
+
+    fn try_from(socket: Async<std::net::UdpSocket>) -> io::Result<Self> {
+        let socket = socket.into_inner()?;
+        socket.set_nonblocking(false)?;
+        Ok(socket)
+    }
+}
+
+#[cfg(feature = "socket2")]
+impl Async<Socket> {
+    /// Connects the socket to the specified address.
+    ///
+    /// The connection is started without blocking and this method completes once it is
+    /// established or has failed. Options that must be set before connecting, such as
+    /// `TCP_FASTOPEN`, can be set on the [`Socket`] before it is wrapped in [`Async`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::new(Socket::new(Domain::IPV4, Type::STREAM, None)?)?;
+    /// let addr = SocketAddr::from(([127, 0, 0, 1], 8000));
+    /// socket.connect(&addr.into()).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn connect(&self, addr: &SockAddr) -> io::Result<()> {
+        // Begin async connect.
+        match self.get_ref().connect(addr) {
+            Ok(()) => return Ok(()),
+            Err(err) if connect_in_progress(&err) => {}
+            Err(err) => return Err(err),
+        }
+
+        // The socket becomes writable when connected.
+        self.writable().await?;
+
+        // Check if there was an error while connecting.
+        match self.get_ref().take_error()? {
+            None => Ok(()),
+            Some(err) => Err(err),
+        }
+    }
+
+    /// Accepts a new incoming connection.
+    ///
+    /// Returns the accepted socket, registered in the reactor, and the address of the peer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Socket::new(Domain::IPV4, Type::STREAM, None)?;
+    /// socket.set_reuse_address(true)?;
+    /// socket.bind(&SocketAddr::from(([127, 0, 0, 1], 8000)).into())?;
+    /// socket.listen(128)?;
+    ///
+    /// let listener = Async::new(socket)?;
+    /// let (stream, addr) = listener.accept().await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn accept(&self) -> io::Result<(Async<Socket>, SockAddr)> {
+        let (socket, addr) = self.read_with(|io| io.accept()).await?;
+        Ok((Async::new(socket)?, addr))
+    }
+
+    /// Receives data from the connected peer.
+    ///
+    /// Returns the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::new(Socket::new(Domain::IPV4, Type::STREAM, None)?)?;
+    /// socket.connect(&SocketAddr::from(([127, 0, 0, 1], 8000)).into()).await?;
+    ///
+    /// let mut buf = [0u8; 1024];
+    /// let len = socket.recv(&mut buf).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn recv(&self, buf: &mut [u8]) -> io::Result<usize> {
+        self.read_with(|io| io.recv(as_uninit(buf))).await
+    }
+
+    /// Receives data and the address it came from.
+    ///
+    /// Returns the number of bytes read and the address of the sender.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Socket::new(Domain::IPV4, Type::DGRAM, None)?;
+    /// socket.bind(&SocketAddr::from(([127, 0, 0, 1], 8000)).into())?;
+    /// let socket = Async::new(socket)?;
+    ///
+    /// let mut buf = [0u8; 1024];
+    /// let (len, addr) = socket.recv_from(&mut buf).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn recv_from(&self, buf: &mut [u8]) -> io::Result<(usize, SockAddr)> {
+        self.read_with(|io| io.recv_from(as_uninit(buf))).await
+    }
+
+    /// Sends data to the connected peer.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::new(Socket::new(Domain::IPV4, Type::STREAM, None)?)?;
+    /// socket.connect(&SocketAddr::from(([127, 0, 0, 1], 8000)).into()).await?;
+    ///
+    /// let len = socket.send(b"hello").await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn send(&self, buf: &[u8]) -> io::Result<usize> {
+        self.write_with(|io| io.send(buf)).await
+    }
+
+    /// Sends data to the specified address.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use async_io::Async;
+    /// use socket2::{Domain, Socket, Type};
+    /// use std::net::SocketAddr;
+    ///
+    /// # futures_lite::future::block_on(async {
+    /// let socket = Async::new(Socket::new(Domain::IPV4, Type::DGRAM, None)?)?;
+    /// let addr = SocketAddr::from(([127, 0, 0, 1], 8000));
+    ///
+    /// let len = socket.send_to(b"hello", &addr.into()).await?;
+    /// # std::io::Result::Ok(()) });
+    /// ```
+    pub async fn send_to(&self, buf: &[u8], addr: &SockAddr) -> io::Result<usize> {
+        self.write_with(|io| io.send_to(buf, addr)).await
+    }
+}
+
+#[cfg(feature = "socket2")]
+impl TryFrom<Socket> for Async<Socket> {
+    type Error = io::Error;
+
+    fn try_from(socket: Socket) -> io::Result<Self> {
+        Async::new(socket)
+    }
+}
+
 #[cfg(unix)]
 impl Async<UnixListener> {
     /// Creates a UDS listener bound to the specified path.
@@ -1659,19 +2755,24 @@ impl Async<UnixListener> {
 
     /// Returns a stream of incoming UDS connections.
     ///
-    /// The stream is infinite, i.e. it never stops with a [`None`] item.
+    /// The stream is infinite, i.e. it never stops with a [`None`] item. Errors, such as a
+    /// connection being aborted before it was accepted or the process running out of file
+    /// descriptors, are yielded as items and do not end the stream. After running out of file
+    /// descriptors, the stream waits a little before accepting again, so that a loop that keeps
+    /// polling it doesn't spin on the same error.
+    ///
+    /// The returned stream is [`Unpin`], so it doesn't need to be pinned before use.
     ///
     /// # Examples
     ///
     /// ```no_run
     /// use async_io::Async;
-    /// use futures_lite::{pin, stream::StreamExt};
+    /// use futures_lite::stream::StreamExt;
     /// use std::os::unix::net::UnixListener;
     ///
     /// # futures_lite::future::block_on(async {
     /// let listener = Async::<UnixListener>::bind("/tmp/socket")?;
-    /// let incoming = listener.incoming();
-    /// pin!(incoming);
+    /// let mut incoming = listener.incoming();
     ///
     /// while let Some(stream) = incoming.next().await {
     ///     let stream = stream?;
@@ -1679,10 +2780,35 @@ impl Async<UnixListener> {
     /// }
     /// # std::io::Result::Ok(()) });
     /// ```
-    pub fn incoming(&self) -> impl Stream<Item = io::Result<Async<UnixStream>>> + Send + '_ {
-        stream::unfold(self, |listener| async move {
-            let res = listener.accept().await.map(|(stream, _)| stream);
-            Some((res, listener))
+    pub fn incoming(
+        &self,
+    ) -> impl Stream<Item = io::Result<Async<UnixStream>>> + Send + Unpin + '_ {
+        let mut backoff: Option<Timer> = None;
+        // Each stream waits with its own future, so that concurrent streams on the same listener
+        // don't keep replacing and waking each other's wakers.
+        let mut readable: Option<Readable<'_, _>> = None;
+
+        stream::poll_fn(move |cx| loop {
+            if let Some(timer) = backoff.as_mut() {
+                ready!(Pin::new(timer).poll(cx));
+                backoff = None;
+            }
+
+            match self.get_ref().accept() {
+                Ok((stream, _)) => return Poll::Ready(Some(Async::new(stream))),
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                Err(err) => {
+                    if out_of_fds(&err) {
+                        backoff = Some(Timer::after(ACCEPT_BACKOFF));
+                    }
+                    return Poll::Ready(Some(Err(err)));
+                }
+            }
+            let res = ready!(Pin::new(readable.get_or_insert_with(|| self.readable())).poll(cx));
+            readable = None;
+            if let Err(err) = res {
+                return Poll::Ready(Some(Err(err)));
+            }
         })
     }
 }
@@ -1696,6 +2822,17 @@ impl TryFrom<std::os::unix::net::UnixListener> for Async<std::os::unix::net::Uni
     }
 }
 
+#[cfg(unix)]
+impl TryFrom<Async<std::os::unix::net::UnixListener>> for std::os::unix::net::UnixListener {
+    type Error = io::Error;
+
+    fn try_from(listener: Async<std::os::unix::net::UnixListener>) -> io::Result<Self> {
+        let listener = listener.into_inner()?;
+        listener.set_nonblocking(false)?;
+        Ok(listener)
+    }
+}
+
 #[cfg(unix)]
 impl Async<UnixStream> {
     /// Creates a UDS stream connected to the specified path.
@@ -1712,8 +2849,9 @@ impl Async<UnixStream> {
     /// ```
     pub async fn connect<P: AsRef<Path>>(path: P) -> io::Result<Async<UnixStream>> {
         // Begin async connect.
-        let socket = connect(SockAddr::unix(path)?, Domain::UNIX, None)?;
-        let stream = Async::new(UnixStream::from(socket))?;
+        let socket = connect(None, SockAddr::unix(path)?, Domain::UNIX, None)?;
+        // The socket was created in non-blocking mode by `connect()`.
+        let stream = Async::new_nonblocking(UnixStream::from(socket))?;
 
         // The stream becomes writable when connected.
         stream.writable().await?;
@@ -1751,6 +2889,17 @@ impl TryFrom<std::os::unix::net::UnixStream> for Async<std::os::unix::net::UnixS
     }
 }
 
+#[cfg(unix)]
+impl TryFrom<Async<std::os::unix::net::UnixStream>> for std::os::unix::net::UnixStream {
+    type Error = io::Error;
+
+    fn try_from(stream: Async<std::os::unix::net::UnixStream>) -> io::Result<Self> {
+        let stream = stream.into_inner()?;
+        stream.set_nonblocking(false)?;
+        Ok(stream)
+    }
+}
+
 #[cfg(unix)]
 impl Async<UnixDatagram> {
     /// Creates a UDS datagram socket bound to the specified path.
@@ -1807,6 +2956,9 @@ impl Async<UnixDatagram> {
     ///
     /// Returns the number of bytes read and the address the message came from.
     ///
+    /// If a message is too long to fit in `buf`, the excess bytes are discarded and only the
+    /// number of bytes actually copied into `buf` is returned.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1853,6 +3005,9 @@ impl Async<UnixDatagram> {
     /// The [`connect`][`UnixDatagram::connect()`] method connects this socket to a remote address.
     /// This method will fail if the socket is not connected.
     ///
+    /// As with [`recv_from()`][`Async::<UnixDatagram>::recv_from()`], a message that is too long
+    /// to fit in `buf` is truncated and the remaining bytes are discarded.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -1906,6 +3061,35 @@ impl TryFrom<std::os::unix::net::UnixDatagram> for Async<std::os::unix::net::Uni
     }
 }
 
+#[cfg(unix)]
+impl TryFrom<Async<std::os::unix::net::UnixDatagram>> for std::os::unix::net::UnixDatagram {
+    type Error = io::Error;
+
+    fn try_from(socket: Async<std::os::unix::net::UnixDatagram>) -> io::Result<Self> {
+        let socket = socket.into_inner()?;
+        socket.set_nonblocking(false)?;
+        Ok(socket)
+    }
+}
+
+/// How long `incoming()` streams wait before accepting again after running out of file descriptors.
+const ACCEPT_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Returns `true` if an error means that the process or the system ran out of file descriptors.
+fn out_of_fds(err: &io::Error) -> bool {
+    cfg_if::cfg_if! {
+        if #[cfg(unix)] {
+            let codes = [rustix::io::Errno::MFILE, rustix::io::Errno::NFILE];
+            codes
+                .iter()
+                .any(|code| err.raw_os_error() == Some(code.raw_os_error()))
+        } else {
+            // WSAEMFILE
+            err.raw_os_error() == Some(10024)
+        }
+    }
+}
+
 /// Polls a future once, waits for a wakeup, and then optimistically assumes the future is ready.
 async fn optimistic(fut: impl Future<Output = io::Result<()>>) -> io::Result<()> {
     let mut polled = false;
@@ -1922,7 +3106,12 @@ async fn optimistic(fut: impl Future<Output = io::Result<()>>) -> io::Result<()>
     .await
 }
 
-fn connect(addr: SockAddr, domain: Domain, protocol: Option<Protocol>) -> io::Result<Socket> {
+fn connect(
+    local: Option<SockAddr>,
+    addr: SockAddr,
+    domain: Domain,
+    protocol: Option<Protocol>,
+) -> io::Result<Socket> {
     let sock_type = Type::STREAM;
     #[cfg(any(
         target_os = "android",
@@ -1950,12 +3139,46 @@ fn connect(addr: SockAddr, domain: Domain, protocol: Option<Protocol>) -> io::Re
     )))]
     // If the current platform doesn't support nonblocking at creation, enable it after creation
     socket.set_nonblocking(true)?;
+    if let Some(local) = local {
+        socket.bind(&local)?;
+    }
     match socket.connect(&addr) {
         Ok(_) => {}
-        #[cfg(unix)]
-        Err(err) if err.raw_os_error() == Some(rustix::io::Errno::INPROGRESS.raw_os_error()) => {}
-        Err(err) if err.kind() == io::ErrorKind::WouldBlock => {}
+        Err(err) if connect_in_progress(&err) => {}
         Err(err) => return Err(err),
     }
     Ok(socket)
 }
+
+/// Returns an error if a file descriptor is a regular file or a directory, which can't be polled.
+#[cfg(unix)]
+fn check_pollable(raw: RawFd) -> io::Result<()> {
+    // Safety: We assume `raw` is a valid fd.
+    let fd = unsafe { rustix::fd::BorrowedFd::borrow_raw(raw) };
+    let mode = rustix::fs::fstat(fd)?.st_mode;
+    match rustix::fs::FileType::from_raw_mode(mode as _) {
+        rustix::fs::FileType::RegularFile | rustix::fs::FileType::Directory => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "regular files and directories cannot be polled; \
+             use a thread pool like `blocking` to do file I/O instead",
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `true` if a non-blocking connect returned an error because it is still in progress.
+fn connect_in_progress(err: &io::Error) -> bool {
+    #[cfg(unix)]
+    if err.raw_os_error() == Some(rustix::io::Errno::INPROGRESS.raw_os_error()) {
+        return true;
+    }
+    err.kind() == io::ErrorKind::WouldBlock
+}
+
+/// Casts an initialized buffer into one that socket2 can receive into.
+#[cfg(feature = "socket2")]
+fn as_uninit(buf: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    // Safety: `MaybeUninit<u8>` has the same layout as `u8`, and the socket only ever writes
+    // initialized bytes into the buffer.
+    unsafe { &mut *(buf as *mut [u8] as *mut [MaybeUninit<u8>]) }
+}
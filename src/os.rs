@@ -11,3 +11,6 @@
     target_os = "dragonfly",
 ))]
 pub mod kqueue;
+
+#[cfg(target_os = "linux")]
+pub mod linux;
@@ -0,0 +1,117 @@
+//! Functionality that is only available on Linux.
+
+use std::io::Result;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::process::{Child, ExitStatus};
+use std::time::Duration;
+
+#[cfg(not(async_io_no_io_safety))]
+use std::os::unix::io::{AsFd, BorrowedFd};
+
+use rustix::fd::OwnedFd;
+use rustix::process::{pidfd_open, Pid, PidfdFlags};
+
+use crate::{Async, Timer};
+
+/// A file descriptor referring to a process, which becomes readable once the process exits.
+///
+/// Registering it with [`Async`] allows waiting for a child process to exit with
+/// [`readable()`](crate::Async::readable) instead of polling [`Child::try_wait()`] in a loop.
+/// Process file descriptors are available since Linux 5.3; on older kernels, [`PidFd::open()`]
+/// fails with an error.
+///
+/// On `kqueue`-based platforms, `os::kqueue::Exit` provides the same functionality.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_io::os::linux::PidFd;
+/// use async_io::Async;
+/// use std::process::Command;
+///
+/// # async_io::block_on(async {
+/// let mut child = Command::new("sleep").arg("1").spawn()?;
+/// let pidfd = Async::new(PidFd::open(&child)?)?;
+///
+/// // Wait for the process to exit, after which reaping it doesn't block.
+/// pidfd.readable().await?;
+/// let status = child.wait()?;
+/// # std::io::Result::Ok(()) });
+/// ```
+#[derive(Debug)]
+pub struct PidFd(OwnedFd);
+
+impl PidFd {
+    /// Opens a file descriptor referring to a child process.
+    ///
+    /// The child must not have been reaped yet, because its process ID could otherwise already
+    /// have been reused by another process.
+    pub fn open(child: &Child) -> Result<PidFd> {
+        Ok(PidFd(pidfd_open(
+            Pid::from_child(child),
+            PidfdFlags::empty(),
+        )?))
+    }
+}
+
+impl AsRawFd for PidFd {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0.as_raw_fd()
+    }
+}
+
+#[cfg(not(async_io_no_io_safety))]
+impl AsFd for PidFd {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+/// Waits for a child process to exit and returns its exit status.
+///
+/// This is an asynchronous version of [`Child::wait()`] built on [`PidFd`]. The process file
+/// descriptor is closed when the returned future completes or is dropped.
+///
+/// On kernels older than Linux 5.3, which lack process file descriptors, this falls back to
+/// checking whether the child has exited with a backoff of up to 100 milliseconds.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::process::Command;
+///
+/// # async_io::block_on(async {
+/// let mut child = Command::new("sleep").arg("1").spawn()?;
+/// let status = async_io::os::linux::wait(&mut child).await?;
+/// # std::io::Result::Ok(()) });
+/// ```
+pub async fn wait(child: &mut Child) -> Result<ExitStatus> {
+    // A child that has already been reaped must not be looked up by its process ID again.
+    if let Some(status) = child.try_wait()? {
+        return Ok(status);
+    }
+
+    let pidfd = match PidFd::open(child) {
+        Ok(pidfd) => Async::new(pidfd)?,
+        Err(err) if err.raw_os_error() == Some(rustix::io::Errno::NOSYS.raw_os_error()) => {
+            return poll_exit(child).await;
+        }
+        Err(err) => return Err(err),
+    };
+    pidfd.readable().await?;
+
+    // The process has exited, so reaping it doesn't block.
+    child.wait()
+}
+
+/// Waits for a child process to exit by checking on it periodically.
+async fn poll_exit(child: &mut Child) -> Result<ExitStatus> {
+    let mut delay = Duration::from_millis(1);
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        Timer::after(delay).await;
+        delay = (delay * 2).min(Duration::from_millis(100));
+    }
+}
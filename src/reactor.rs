@@ -83,6 +83,21 @@ pub(crate) struct Reactor {
     /// When inserting or removing a timer, we don't process it immediately - we just push it into
     /// this queue. Timers actually get processed when the queue fills up or the reactor is polled.
     timer_ops: ConcurrentQueue<TimerOp>,
+
+    /// The instant at which the reactor was created.
+    ///
+    /// Deadlines of coalesced timers are rounded relative to this instant so that timers with the
+    /// same granularity end up in the same buckets.
+    epoch: Instant,
+
+    /// Number of times the poller has been waited on.
+    waits: AtomicUsize,
+
+    /// Number of I/O events delivered by the poller.
+    events_delivered: AtomicUsize,
+
+    /// Number of times the poller has been notified.
+    notifications: AtomicUsize,
 }
 
 impl Reactor {
@@ -99,10 +114,19 @@ impl Reactor {
                 events: Mutex::new(Vec::new()),
                 timers: Mutex::new(BTreeMap::new()),
                 timer_ops: ConcurrentQueue::bounded(1000),
+                epoch: Instant::now(),
+                waits: AtomicUsize::new(0),
+                events_delivered: AtomicUsize::new(0),
+                notifications: AtomicUsize::new(0),
             }
         })
     }
 
+    /// Returns the instant at which the reactor was created.
+    pub(crate) fn epoch(&self) -> Instant {
+        self.epoch
+    }
+
     /// Returns the current ticker.
     pub(crate) fn ticker(&self) -> usize {
         self.ticker.load(Ordering::SeqCst)
@@ -130,11 +154,16 @@ impl Reactor {
             return Err(err);
         }
 
+        tracing::trace!(key = source.key, fd = ?source.registration, "registered source");
         Ok(source)
     }
 
     /// Deregisters an I/O source from the reactor.
+    ///
+    /// The source is forgotten even if deregistration fails, e.g. because its file descriptor
+    /// has already been closed.
     pub(crate) fn remove_io(&self, source: &Source) -> io::Result<()> {
+        tracing::trace!(key = source.key, "deregistering source");
         let mut sources = self.sources.lock().unwrap();
         sources.remove(source.key);
         source.registration.delete(&self.poller)
@@ -165,6 +194,25 @@ impl Reactor {
         id
     }
 
+    /// Moves a registered timer from `old` to a new deadline, keeping its ID.
+    ///
+    /// The timer doesn't have to be in the queue anymore, which is the case once it has fired.
+    pub(crate) fn reschedule_timer(&self, old: Instant, when: Instant, id: usize, waker: &Waker) {
+        // Push a reschedule operation.
+        while self
+            .timer_ops
+            .push(TimerOp::Reschedule(old, when, id, waker.clone()))
+            .is_err()
+        {
+            // If the queue is full, drain it and try again.
+            let mut timers = self.timers.lock().unwrap();
+            self.process_timer_ops(&mut timers);
+        }
+
+        // Notify that a timer has been rescheduled.
+        self.notify();
+    }
+
     /// Deregisters a timer from the reactor.
     pub(crate) fn remove_timer(&self, when: Instant, id: usize) {
         // Push a remove operation.
@@ -177,9 +225,29 @@ impl Reactor {
 
     /// Notifies the thread blocked on the reactor.
     pub(crate) fn notify(&self) {
+        self.notifications.fetch_add(1, Ordering::Relaxed);
         self.poller.notify().expect("failed to notify reactor");
     }
 
+    /// Takes a snapshot of the reactor's state and counters.
+    pub(crate) fn metrics(&self) -> ReactorMetrics {
+        let sources = self.sources.lock().unwrap().len();
+        let (timers, next_timer) = {
+            let mut timers = self.timers.lock().unwrap();
+            self.process_timer_ops(&mut timers);
+            (timers.len(), timers.keys().next().map(|(when, _)| *when))
+        };
+
+        ReactorMetrics {
+            sources,
+            timers,
+            next_timer,
+            waits: self.waits.load(Ordering::Relaxed),
+            events: self.events_delivered.load(Ordering::Relaxed),
+            notifications: self.notifications.load(Ordering::Relaxed),
+        }
+    }
+
     /// Locks the reactor, potentially blocking if the lock is held by another thread.
     pub(crate) fn lock(&self) -> ReactorLock<'_> {
         let reactor = self;
@@ -232,7 +300,17 @@ impl Reactor {
         // Add wakers to the list.
         tracing::trace!("{} ready wakers", ready.len());
 
-        for (_, waker) in ready {
+        for ((when, id), waker) in ready {
+            tracing::trace!(id, ?when, "timer fired");
+
+            // Without the "async-io" thread, timers only fire while something drives the reactor.
+            let late = now.saturating_duration_since(when);
+            if late > Duration::from_secs(1) && crate::driver::driver_thread_disabled() {
+                tracing::warn!(
+                    "timer fired {:?} late, is anything driving the reactor with block_on()?",
+                    late
+                );
+            }
             wakers.push(waker);
         }
 
@@ -250,6 +328,10 @@ impl Reactor {
                 TimerOp::Insert(when, id, waker) => {
                     timers.insert((when, id), waker);
                 }
+                TimerOp::Reschedule(old, when, id, waker) => {
+                    timers.remove(&(old, id));
+                    timers.insert((when, id), waker);
+                }
                 TimerOp::Remove(when, id) => {
                     timers.remove(&(when, id));
                 }
@@ -257,6 +339,67 @@ impl Reactor {
     }
 }
 
+/// Returns a snapshot of the reactor's state and activity counters.
+///
+/// This is cheap enough to be called periodically, e.g. to export the values to a monitoring
+/// system.
+///
+/// # Examples
+///
+/// ```
+/// let metrics = async_io::reactor_metrics();
+/// println!("{} sources, {} timers", metrics.sources(), metrics.timers());
+/// ```
+pub fn reactor_metrics() -> ReactorMetrics {
+    Reactor::get().metrics()
+}
+
+/// A snapshot of the reactor's state and activity counters, returned by [`reactor_metrics()`].
+///
+/// The counters only ever increase, wrapping around on overflow, so rates can be computed from
+/// the difference between two snapshots.
+#[derive(Debug, Clone)]
+pub struct ReactorMetrics {
+    sources: usize,
+    timers: usize,
+    next_timer: Option<Instant>,
+    waits: usize,
+    events: usize,
+    notifications: usize,
+}
+
+impl ReactorMetrics {
+    /// Returns the number of I/O sources registered in the reactor.
+    pub fn sources(&self) -> usize {
+        self.sources
+    }
+
+    /// Returns the number of pending timers.
+    pub fn timers(&self) -> usize {
+        self.timers
+    }
+
+    /// Returns the deadline of the earliest pending timer, if any.
+    pub fn next_timer(&self) -> Option<Instant> {
+        self.next_timer
+    }
+
+    /// Returns how many times the reactor has waited for I/O events.
+    pub fn waits(&self) -> usize {
+        self.waits
+    }
+
+    /// Returns how many I/O events the reactor has received.
+    pub fn events(&self) -> usize {
+        self.events
+    }
+
+    /// Returns how many times the reactor has been woken up by a notification.
+    pub fn notifications(&self) -> usize {
+        self.notifications
+    }
+}
+
 /// A lock on the reactor.
 pub(crate) struct ReactorLock<'a> {
     reactor: &'a Reactor,
@@ -291,7 +434,12 @@ impl ReactorLock<'_> {
         self.events.clear();
 
         // Block on I/O events.
-        let res = match self.reactor.poller.wait(&mut self.events, timeout) {
+        tracing::trace!(?timeout, "waiting for I/O events");
+        self.reactor.waits.fetch_add(1, Ordering::Relaxed);
+        let res = self.reactor.poller.wait(&mut self.events, timeout);
+        tracing::trace!(events = ?res.as_ref().ok(), "done waiting for I/O events");
+
+        let res = match res {
             // No I/O events occurred.
             Ok(0) => {
                 if timeout != Some(Duration::from_secs(0)) {
@@ -302,11 +450,22 @@ impl ReactorLock<'_> {
             }
 
             // At least one I/O event occurred.
-            Ok(_) => {
+            Ok(n) => {
+                self.reactor
+                    .events_delivered
+                    .fetch_add(n, Ordering::Relaxed);
+
                 // Iterate over sources in the event list.
                 let sources = self.reactor.sources.lock().unwrap();
 
                 for ev in self.events.iter() {
+                    tracing::trace!(
+                        key = ev.key,
+                        readable = ev.readable,
+                        writable = ev.writable,
+                        "received I/O event"
+                    );
+
                     // Check if there is a source in the table with this key.
                     if let Some(source) = sources.get(ev.key) {
                         let mut state = source.state.lock().unwrap();
@@ -323,14 +482,7 @@ impl ReactorLock<'_> {
                         // e.g. we were previously interested in both readability and writability,
                         // but only one of them was emitted.
                         if !state[READ].is_empty() || !state[WRITE].is_empty() {
-                            source.registration.modify(
-                                &self.reactor.poller,
-                                Event {
-                                    key: source.key,
-                                    readable: !state[READ].is_empty(),
-                                    writable: !state[WRITE].is_empty(),
-                                },
-                            )?;
+                            source.update_interest(&state)?;
                         }
                     }
                 }
@@ -338,8 +490,14 @@ impl ReactorLock<'_> {
                 Ok(())
             }
 
-            // The syscall was interrupted.
-            Err(err) if err.kind() == io::ErrorKind::Interrupted => Ok(()),
+            // The syscall was interrupted by a signal, which is the same as no I/O events.
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {
+                if timeout != Some(Duration::from_secs(0)) {
+                    // The wait may have been cut short right at the deadline, so fire ready timers.
+                    self.reactor.process_timers(&mut wakers);
+                }
+                Ok(())
+            }
 
             // An actual error occureed.
             Err(err) => Err(err),
@@ -359,6 +517,7 @@ impl ReactorLock<'_> {
 /// A single timer operation.
 enum TimerOp {
     Insert(Instant, usize, Waker),
+    Reschedule(Instant, Instant, usize, Waker),
     Remove(Instant, usize),
 }
 
@@ -413,6 +572,22 @@ impl Direction {
 }
 
 impl Source {
+    /// Updates interest in this I/O handle to the directions that have wakers.
+    fn update_interest(&self, state: &[Direction; 2]) -> io::Result<()> {
+        let readable = !state[READ].is_empty();
+        let writable = !state[WRITE].is_empty();
+        tracing::trace!(key = self.key, readable, writable, "updating interest");
+
+        self.registration.modify(
+            &Reactor::get().poller,
+            Event {
+                key: self.key,
+                readable,
+                writable,
+            },
+        )
+    }
+
     /// Polls the I/O source for readability.
     pub(crate) fn poll_readable(&self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         self.poll_ready(READ, cx)
@@ -455,14 +630,7 @@ impl Source {
 
         // Update interest in this I/O handle.
         if was_empty {
-            self.registration.modify(
-                &Reactor::get().poller,
-                Event {
-                    key: self.key,
-                    readable: !state[READ].is_empty(),
-                    writable: !state[WRITE].is_empty(),
-                },
-            )?;
+            self.update_interest(&state)?;
         }
 
         Poll::Pending
@@ -629,14 +797,7 @@ impl<H: Borrow<crate::Async<T>> + Clone, T> Future for Ready<H, T> {
 
         // Update interest in this I/O handle.
         if was_empty {
-            handle.borrow().source.registration.modify(
-                &Reactor::get().poller,
-                Event {
-                    key: handle.borrow().source.key,
-                    readable: !state[READ].is_empty(),
-                    writable: !state[WRITE].is_empty(),
-                },
-            )?;
+            handle.borrow().source.update_interest(&state)?;
         }
 
         Poll::Pending
@@ -647,10 +808,14 @@ impl<H: Borrow<crate::Async<T>>, T> Drop for Ready<H, T> {
     fn drop(&mut self) {
         // Remove our waker when dropped.
         if let Some(key) = self.index {
-            let mut state = self.handle.borrow().source.state.lock().unwrap();
+            let source = &self.handle.borrow().source;
+            let mut state = source.state.lock().unwrap();
             let wakers = &mut state[self.dir].wakers;
-            if wakers.contains(key) {
-                wakers.remove(key);
+            if wakers.contains(key) && wakers.remove(key).is_some() && state[self.dir].is_empty() {
+                // Nobody is waiting on this direction anymore, so update interest in this I/O
+                // handle. With no interest left at all, this disarms the source until the next
+                // waker is registered. Errors are ignored because destructors should not fail.
+                source.update_interest(&state).ok();
             }
         }
     }
@@ -1,16 +1,20 @@
 use std::future::Future;
-use std::io;
-use std::net::{Shutdown, TcpListener, TcpStream, UdpSocket};
+use std::io::{self, IoSlice, IoSliceMut};
+use std::net::{Shutdown, SocketAddr, TcpListener, TcpStream, UdpSocket};
 #[cfg(unix)]
 use std::os::unix::net::{UnixDatagram, UnixListener, UnixStream};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::task::{Context, Poll};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_io::{Async, Timer};
-use futures_lite::{future, prelude::*};
+use futures_lite::{future, prelude::*, ready};
 #[cfg(unix)]
 use tempfile::tempdir;
+use waker_fn::waker_fn;
 
 const LOREM_IPSUM: &[u8] = b"
 Lorem ipsum dolor sit amet, consectetur adipiscing elit.
@@ -60,6 +64,147 @@ fn tcp_connect() -> io::Result<()> {
     })
 }
 
+#[cfg(target_os = "linux")]
+#[test]
+fn tcp_bind_reuseport() -> io::Result<()> {
+    future::block_on(async {
+        let listener1 = Async::<TcpListener>::bind_reuseport(([127, 0, 0, 1], 0))?;
+        let addr = listener1.get_ref().local_addr()?;
+        let listener2 = Async::<TcpListener>::bind_reuseport(addr)?;
+        assert_eq!(listener2.get_ref().local_addr()?, addr);
+
+        // The kernel hands each connection to one of the listeners.
+        let accept = listener1.accept().or(listener2.accept());
+        let (stream, _) = future::try_zip(Async::<TcpStream>::connect(addr), accept).await?;
+        assert_eq!(stream.get_ref().peer_addr()?, addr);
+
+        Ok(())
+    })
+}
+
+#[cfg(feature = "socket2")]
+#[test]
+fn socket2_stream() -> io::Result<()> {
+    use socket2::{Domain, Socket, Type};
+
+    future::block_on(async {
+        let socket = Socket::new(Domain::IPV4, Type::STREAM, None)?;
+        socket.bind(&SocketAddr::from(([127, 0, 0, 1], 0)).into())?;
+        socket.listen(128)?;
+        let listener = Async::new(socket)?;
+        let addr = listener.get_ref().local_addr()?;
+
+        let client = Async::new(Socket::new(Domain::IPV4, Type::STREAM, None)?)?;
+        let ((server, peer), ()) =
+            future::try_zip(listener.accept(), client.connect(&addr)).await?;
+        assert_eq!(peer.as_socket(), client.get_ref().local_addr()?.as_socket());
+
+        client.send(LOREM_IPSUM).await?;
+        let mut buf = vec![0; LOREM_IPSUM.len()];
+        let mut len = 0;
+        while len < buf.len() {
+            len += server.recv(&mut buf[len..]).await?;
+        }
+        assert_eq!(buf, LOREM_IPSUM);
+
+        // Connecting to a closed port reports the error once the attempt fails.
+        drop(listener);
+        let client = Async::new(Socket::new(Domain::IPV4, Type::STREAM, None)?)?;
+        let err = client.connect(&addr).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+
+        Ok(())
+    })
+}
+
+#[cfg(feature = "socket2")]
+#[test]
+fn socket2_datagram() -> io::Result<()> {
+    use socket2::{Domain, Socket, Type};
+
+    future::block_on(async {
+        let bind = || -> io::Result<Async<Socket>> {
+            let socket = Socket::new(Domain::IPV4, Type::DGRAM, None)?;
+            socket.bind(&SocketAddr::from(([127, 0, 0, 1], 0)).into())?;
+            Async::new(socket)
+        };
+        let socket1 = bind()?;
+        let socket2 = bind()?;
+        let addr1 = socket1.get_ref().local_addr()?;
+        let addr2 = socket2.get_ref().local_addr()?;
+
+        let mut buf = [0; 1024];
+        let ((len, from), _) = future::try_zip(
+            socket2.recv_from(&mut buf),
+            socket1.send_to(&LOREM_IPSUM[..10], &addr2),
+        )
+        .await?;
+        assert_eq!(&buf[..len], &LOREM_IPSUM[..10]);
+        assert_eq!(from.as_socket(), addr1.as_socket());
+
+        Ok(())
+    })
+}
+
+#[test]
+fn try_from_round_trip() -> io::Result<()> {
+    use std::convert::TryFrom;
+
+    let socket = UdpSocket::bind("127.0.0.1:0")?;
+    let socket = Async::try_from(socket)?;
+    let socket = UdpSocket::try_from(socket)?;
+
+    // The socket is back in blocking mode, so a receive waits for the timeout.
+    let timeout = Duration::from_millis(50);
+    socket.set_read_timeout(Some(timeout))?;
+    let start = Instant::now();
+    let mut buf = [0; 1];
+    let err = socket.recv(&mut buf).unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+    ));
+    assert!(start.elapsed() >= timeout - Duration::from_millis(5));
+
+    // The socket can be registered again.
+    let socket = Async::try_from(socket)?;
+    let addr = socket.get_ref().local_addr()?;
+    future::block_on(async {
+        let sender = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        sender.send_to(b"x", addr).await?;
+        socket.recv(&mut buf).await
+    })?;
+
+    Ok(())
+}
+
+#[test]
+fn tcp_connect_from() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+
+        // Reserve a local port to connect from.
+        let local = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+
+        let ((server, peer), client) = future::try_zip(
+            listener.accept(),
+            Async::<TcpStream>::connect_from(local, addr),
+        )
+        .await?;
+        assert_eq!(client.get_ref().local_addr()?, local);
+        assert_eq!(peer, local);
+        assert_eq!(server.get_ref().peer_addr()?, local);
+
+        let err = Async::<TcpStream>::connect_from(([0; 16], 0), addr)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        Ok(())
+    })
+}
+
 #[test]
 fn tcp_peek_read() -> io::Result<()> {
     future::block_on(async {
@@ -82,6 +227,189 @@ fn tcp_peek_read() -> io::Result<()> {
     })
 }
 
+#[test]
+fn tcp_peek_waits() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        // An empty buffer doesn't wait for any data.
+        assert_eq!(server.peek(&mut []).await?, 0);
+
+        let task = spawn(async move {
+            Timer::after(Duration::from_millis(100)).await;
+            client.write_all(b"hello").await?;
+            io::Result::Ok(client)
+        });
+
+        let mut buf = [0; 5];
+        let n = server.peek(&mut buf).await?;
+        assert_eq!(&buf[..n], &b"hello"[..n]);
+        server.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+
+        task.await?;
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_half_close() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        // The request ends with a half-close.
+        client.write_all(b"ping").await?;
+        client.shutdown(Shutdown::Write)?;
+        assert!(client.write_all(b"more").await.is_err());
+
+        let mut request = Vec::new();
+        server.read_to_end(&mut request).await?;
+        assert_eq!(request, b"ping");
+
+        // The response still arrives over the other half.
+        server.write_all(b"pong").await?;
+        drop(server);
+        let mut response = Vec::new();
+        client.read_to_end(&mut response).await?;
+        assert_eq!(response, b"pong");
+
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_read_cancelled() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        // Give up on a read before any data arrives.
+        let mut buf = vec![0; LOREM_IPSUM.len()];
+        assert!(future::poll_once(server.read(&mut buf)).await.is_none());
+        assert!(future::poll_once(server.read_with(|s| s.peek(&mut [0; 1])))
+            .await
+            .is_none());
+
+        // Nothing has been consumed, so the data can still be read in full.
+        client.write_all(LOREM_IPSUM).await?;
+        server.read_exact(&mut buf).await?;
+        assert_eq!(buf, LOREM_IPSUM);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_incoming() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+
+        let clients = spawn(async move {
+            let mut clients = Vec::new();
+            for _ in 0..3 {
+                clients.push(Async::<TcpStream>::connect(addr).await?);
+                Timer::after(Duration::from_millis(10)).await;
+            }
+            io::Result::Ok(clients)
+        });
+
+        // The stream is `Unpin`, so it can be used without pinning.
+        let mut incoming = listener.incoming().take(3);
+        let mut accepted = 0;
+        while let Some(stream) = incoming.next().await {
+            stream?;
+            accepted += 1;
+        }
+        assert_eq!(accepted, 3);
+
+        clients.await?;
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_incoming_concurrent() -> io::Result<()> {
+    let listener = Arc::new(Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?);
+    let polls = Arc::new(AtomicUsize::new(0));
+
+    // Two tasks wait on the same idle listener.
+    let consumers: Vec<_> = (0..2)
+        .map(|_| {
+            let listener = listener.clone();
+            let polls = polls.clone();
+            spawn(async move {
+                let mut incoming = listener.incoming();
+                let next = future::poll_fn(|cx| {
+                    polls.fetch_add(1, Ordering::SeqCst);
+                    incoming.poll_next(cx)
+                });
+                let timeout = async {
+                    Timer::after(Duration::from_millis(500)).await;
+                    None
+                };
+                future::or(next, timeout).await.is_none()
+            })
+        })
+        .collect();
+
+    for consumer in consumers {
+        assert!(future::block_on(consumer));
+    }
+
+    // The streams don't keep waking each other up.
+    let polls = polls.load(Ordering::SeqCst);
+    assert!(polls <= 10, "{} polls on an idle listener", polls);
+    Ok(())
+}
+
+#[test]
+fn tcp_socket_options() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((server, _), client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        client.set_nodelay(true)?;
+        assert!(client.nodelay()?);
+        client.set_nodelay(false)?;
+        assert!(!client.nodelay()?);
+
+        server.set_ttl(42)?;
+        assert_eq!(server.ttl()?, 42);
+
+        Ok(())
+    })
+}
+
+#[cfg(feature = "socket2")]
+#[test]
+fn tcp_keepalive() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((server, _), _client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        let keepalive = socket2::TcpKeepalive::new().with_time(Duration::from_secs(60));
+        server.set_keepalive(Some(&keepalive))?;
+        assert!(server.keepalive()?);
+        server.set_keepalive(None)?;
+        assert!(!server.keepalive()?);
+
+        Ok(())
+    })
+}
+
 #[test]
 fn tcp_reader_hangup() -> io::Result<()> {
     future::block_on(async {
@@ -128,6 +456,52 @@ fn tcp_writer_hangup() -> io::Result<()> {
     })
 }
 
+#[test]
+fn udp_readable_two_waiters() -> io::Result<()> {
+    future::block_on(async {
+        let socket = Arc::new(Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?);
+        let addr = socket.get_ref().local_addr()?;
+
+        let task1 = spawn(socket.clone().readable_owned());
+        let task2 = spawn(socket.clone().readable_owned());
+
+        // Both tasks are waiting by now, and a single datagram wakes them both.
+        Timer::after(Duration::from_millis(100)).await;
+        UdpSocket::bind("127.0.0.1:0")?.send_to(b"hello", addr)?;
+
+        task1.await?;
+        task2.await?;
+        Ok(())
+    })
+}
+
+#[test]
+fn udp_connected() -> io::Result<()> {
+    future::block_on(async {
+        let socket1 = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        let socket2 = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        socket1.connect(socket2.get_ref().local_addr()?)?;
+        socket2.connect(socket1.get_ref().local_addr()?)?;
+
+        let mut buf = [0u8; 1024];
+        for _ in 0..3 {
+            // Receiving waits until the peer's datagram arrives.
+            let (n, ()) = future::try_zip(socket2.recv(&mut buf), async {
+                Timer::after(Duration::from_millis(10)).await;
+                socket1.send(LOREM_IPSUM).await.map(drop)
+            })
+            .await?;
+            assert_eq!(&buf[..n], LOREM_IPSUM);
+
+            socket2.send(&LOREM_IPSUM[..10]).await?;
+            let n = socket1.recv(&mut buf).await?;
+            assert_eq!(&buf[..n], &LOREM_IPSUM[..10]);
+        }
+
+        Ok(())
+    })
+}
+
 #[test]
 fn udp_send_recv() -> io::Result<()> {
     future::block_on(async {
@@ -155,6 +529,32 @@ fn udp_send_recv() -> io::Result<()> {
     })
 }
 
+// Loopback multicast delivery depends on the platform's routing setup, so only test it on Linux.
+#[cfg(target_os = "linux")]
+#[test]
+fn udp_multicast() -> io::Result<()> {
+    use std::net::Ipv4Addr;
+
+    future::block_on(async {
+        let group = Ipv4Addr::new(239, 255, 42, 99);
+        let receiver = Async::<UdpSocket>::bind(([0, 0, 0, 0], 0))?;
+        let port = receiver.get_ref().local_addr()?.port();
+
+        receiver.join_multicast_v4(group, Ipv4Addr::LOCALHOST)?;
+
+        let sender = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        sender.set_multicast_loop_v4(true)?;
+        sender.send_to(LOREM_IPSUM, (group, port)).await?;
+
+        let mut buf = [0; 1024];
+        let n = receiver.recv_from(&mut buf).await?.0;
+        assert_eq!(&buf[..n], LOREM_IPSUM);
+
+        receiver.leave_multicast_v4(group, Ipv4Addr::LOCALHOST)?;
+        Ok(())
+    })
+}
+
 #[cfg(unix)]
 #[test]
 fn udp_connect() -> io::Result<()> {
@@ -211,6 +611,34 @@ fn uds_connect() -> io::Result<()> {
     })
 }
 
+#[cfg(unix)]
+#[test]
+fn uds_incoming() -> io::Result<()> {
+    future::block_on(async {
+        let dir = tempdir()?;
+        let path = dir.path().join("socket");
+        let listener = Async::<UnixListener>::bind(&path)?;
+
+        let clients = spawn(async move {
+            let mut clients = Vec::new();
+            for _ in 0..3 {
+                clients.push(Async::<UnixStream>::connect(&path).await?);
+            }
+            io::Result::Ok(clients)
+        });
+
+        let accepted = listener
+            .incoming()
+            .take(3)
+            .try_collect::<_, _, Vec<_>>()
+            .await?;
+        assert_eq!(accepted.len(), 3);
+
+        clients.await?;
+        Ok(())
+    })
+}
+
 #[cfg(unix)]
 #[test]
 fn uds_send_recv() -> io::Result<()> {
@@ -244,6 +672,26 @@ fn uds_send_to_recv_from() -> io::Result<()> {
     })
 }
 
+#[cfg(unix)]
+#[test]
+fn uds_datagram_truncated() -> io::Result<()> {
+    future::block_on(async {
+        let (socket1, socket2) = Async::<UnixDatagram>::pair()?;
+
+        socket1.send(LOREM_IPSUM).await?;
+        socket1.send(b"hello").await?;
+
+        // The rest of the first message is discarded rather than returned by the next call.
+        let mut buf = [0; 8];
+        let n = socket2.recv(&mut buf).await?;
+        assert_eq!(&buf[..n], &LOREM_IPSUM[..8]);
+        let n = socket2.recv(&mut buf).await?;
+        assert_eq!(&buf[..n], b"hello");
+
+        Ok(())
+    })
+}
+
 #[cfg(unix)]
 #[test]
 fn uds_reader_hangup() -> io::Result<()> {
@@ -355,3 +803,520 @@ fn shutdown() -> io::Result<()> {
         Ok(())
     })
 }
+
+#[test]
+fn readable_writable_concurrently() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut peer, _), stream) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+        let stream = Arc::new(stream);
+
+        // One task waits for readability while another waits for writability.
+        let reader = spawn({
+            let stream = stream.clone();
+            async move { stream.readable().await }
+        });
+        let writer = spawn({
+            let stream = stream.clone();
+            async move { stream.writable().await }
+        });
+
+        writer.await?;
+        peer.write_all(LOREM_IPSUM).await?;
+        reader.await?;
+
+        // Waiting for readiness must not consume any data.
+        let mut buf = [0; 1024];
+        let n = (&*stream).read(&mut buf).await?;
+        assert_eq!(&buf[..n], LOREM_IPSUM);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn poll_readable_waker_replacement() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut peer, _), stream) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        let wakes1 = Arc::new(AtomicUsize::new(0));
+        let wakes2 = Arc::new(AtomicUsize::new(0));
+        let waker1 = waker_fn({
+            let wakes1 = wakes1.clone();
+            move || {
+                wakes1.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        let waker2 = waker_fn({
+            let wakes2 = wakes2.clone();
+            move || {
+                wakes2.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        assert!(stream
+            .poll_readable(&mut Context::from_waker(&waker1))
+            .is_pending());
+
+        // Polling from another task replaces the registered waker and wakes the old one.
+        assert!(stream
+            .poll_readable(&mut Context::from_waker(&waker2))
+            .is_pending());
+        assert_eq!(wakes1.load(Ordering::SeqCst), 1);
+
+        // Deliver an event and wait until the reactor wakes the registered waker.
+        peer.write_all(LOREM_IPSUM).await?;
+        while wakes2.load(Ordering::SeqCst) == 0 {
+            Timer::after(Duration::from_millis(1)).await;
+        }
+
+        // Polling again after the wakeup reports readability.
+        future::poll_fn(|cx| stream.poll_readable(cx)).await?;
+        assert_eq!(wakes1.load(Ordering::SeqCst), 1);
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn read_write_with_mut() -> io::Result<()> {
+    use std::os::unix::io::{AsRawFd, OwnedFd, RawFd};
+
+    /// A pipe end whose I/O methods need `&mut self`.
+    struct Counted {
+        fd: OwnedFd,
+        ops: usize,
+    }
+
+    impl Counted {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.ops += 1;
+            Ok(rustix::io::read(&self.fd, buf)?)
+        }
+
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.ops += 1;
+            Ok(rustix::io::write(&self.fd, buf)?)
+        }
+    }
+
+    impl AsRawFd for Counted {
+        fn as_raw_fd(&self) -> RawFd {
+            self.fd.as_raw_fd()
+        }
+    }
+
+    future::block_on(async {
+        let (reader, writer) = rustix::io::pipe()?;
+        let mut reader = Async::new(Counted { fd: reader, ops: 0 })?;
+        let mut writer = Async::new(Counted { fd: writer, ops: 0 })?;
+
+        let mut buf = [0; 1024];
+        let (n, _) = future::try_zip(reader.read_with_mut(|r| r.read(&mut buf)), async {
+            Timer::after(Duration::from_millis(10)).await;
+            writer.write_with_mut(|w| w.write(LOREM_IPSUM)).await
+        })
+        .await?;
+        assert_eq!(&buf[..n], LOREM_IPSUM);
+
+        // The reader hit `WouldBlock` before the data arrived, and was retried afterwards.
+        assert!(reader.get_ref().ops >= 2);
+        assert_eq!(writer.get_ref().ops, 1);
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn new_nonblocking_raw_fd() -> io::Result<()> {
+    use rustix::fs::{fcntl_getfl, OFlags};
+    use std::os::unix::io::AsRawFd;
+
+    future::block_on(async {
+        // A pipe whose lifetime is managed outside of `Async`.
+        let (reader, writer) = rustix::io::pipe()?;
+        let source = Async::new_nonblocking(reader.as_raw_fd())?;
+
+        // The file descriptor's flags are left untouched.
+        assert!(!fcntl_getfl(&reader)?.contains(OFlags::NONBLOCK));
+
+        rustix::io::write(&writer, LOREM_IPSUM)?;
+        source.readable().await?;
+
+        // Dropping the source does not close the file descriptor.
+        drop(source);
+        let mut buf = [0; 1024];
+        let n = rustix::io::read(&reader, &mut buf)?;
+        assert_eq!(&buf[..n], LOREM_IPSUM);
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn uds_into_raw_fd() -> io::Result<()> {
+    use std::os::unix::io::{FromRawFd, IntoRawFd};
+
+    future::block_on(async {
+        let (stream1, mut stream2) = Async::<UnixStream>::pair()?;
+
+        // The descriptor stays open and is no longer registered in the reactor.
+        let fd = stream1.into_raw_fd();
+        let stream1 = unsafe { UnixStream::from_raw_fd(fd) };
+
+        // So it can be registered again.
+        let mut stream1 = Async::new(stream1)?;
+        stream1.write_all(LOREM_IPSUM).await?;
+        let mut buf = vec![0; LOREM_IPSUM.len()];
+        stream2.read_exact(&mut buf).await?;
+        assert_eq!(buf, LOREM_IPSUM);
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn uds_relay() -> io::Result<()> {
+    future::block_on(async {
+        let (mut source, relay_in) = Async::<UnixStream>::pair()?;
+        let (relay_out, mut sink) = Async::<UnixStream>::pair()?;
+
+        let data: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+        let expected = data.clone();
+
+        let writer = spawn(async move {
+            source.write_all(&data).await?;
+            source.get_ref().shutdown(Shutdown::Write)
+        });
+        let relay = spawn(async move {
+            let copied = futures_lite::io::copy(&relay_in, &mut &relay_out).await?;
+            relay_out.get_ref().shutdown(Shutdown::Write)?;
+            io::Result::Ok(copied)
+        });
+
+        let mut received = Vec::new();
+        sink.read_to_end(&mut received).await?;
+        writer.await?;
+        assert_eq!(relay.await?, expected.len() as u64);
+        assert!(received == expected);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn poll_readable_manual_future() -> io::Result<()> {
+    /// A hand-written future that receives a datagram using `poll_readable()`.
+    struct Recv<'a> {
+        socket: &'a Async<UdpSocket>,
+        buf: &'a mut [u8],
+    }
+
+    impl Future for Recv<'_> {
+        type Output = io::Result<usize>;
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = &mut *self;
+            loop {
+                match this.socket.get_ref().recv(this.buf) {
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => {}
+                    res => return Poll::Ready(res),
+                }
+                ready!(this.socket.poll_readable(cx))?;
+            }
+        }
+    }
+
+    async_io::block_on(async {
+        let socket1 = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        let socket2 = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+        socket1.get_ref().connect(socket2.get_ref().local_addr()?)?;
+
+        let mut buf = [0u8; 1024];
+        let recv = Recv {
+            socket: &socket2,
+            buf: &mut buf,
+        };
+        let (n, _) = future::try_zip(recv, async {
+            Timer::after(Duration::from_millis(10)).await;
+            socket1.send(LOREM_IPSUM).await
+        })
+        .await?;
+        assert_eq!(&buf[..n], LOREM_IPSUM);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn readiness_owned() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut peer, _), stream) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+        let stream = Arc::new(stream);
+
+        // The owned futures are `'static`, so they can be moved into other threads.
+        spawn(stream.clone().writable_owned()).await?;
+
+        let task = spawn(stream.clone().readable_owned());
+        peer.write_all(LOREM_IPSUM).await?;
+        task.await?;
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn uds_vectored() -> io::Result<()> {
+    future::block_on(async {
+        let (mut socket1, mut socket2) = Async::<UnixStream>::pair()?;
+
+        let (header, body) = LOREM_IPSUM.split_at(16);
+        let n = socket1
+            .write_vectored(&[IoSlice::new(header), IoSlice::new(body)])
+            .await?;
+        assert_eq!(n, LOREM_IPSUM.len());
+
+        let mut buf1 = [0; 16];
+        let mut buf2 = [0; 1024];
+        let n = socket2
+            .read_vectored(&mut [IoSliceMut::new(&mut buf1), IoSliceMut::new(&mut buf2)])
+            .await?;
+        assert_eq!(n, LOREM_IPSUM.len());
+        assert_eq!(&buf1, header);
+        assert_eq!(&buf2[..n - 16], body);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_vectored() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((server, _), client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        // Go through the `&Async<T>` impls so both sides can be shared.
+        let (header, body) = LOREM_IPSUM.split_at(16);
+        let n = (&client)
+            .write_vectored(&[IoSlice::new(header), IoSlice::new(body)])
+            .await?;
+        assert_eq!(n, LOREM_IPSUM.len());
+
+        let mut buf1 = [0; 16];
+        let mut buf2 = [0; 1024];
+        let n = (&server)
+            .read_vectored(&mut [IoSliceMut::new(&mut buf1), IoSliceMut::new(&mut buf2)])
+            .await?;
+        assert_eq!(n, LOREM_IPSUM.len());
+        assert_eq!(&buf1, header);
+        assert_eq!(&buf2[..n - 16], body);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_split() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((server, _), client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        // Echo everything back from separate reader and writer tasks.
+        let (mut reader, mut writer) = server.split();
+        let (s, r) = async_channel::unbounded::<Vec<u8>>();
+        let read_task = spawn(async move {
+            let mut buf = [0; 1024];
+            loop {
+                let n = reader.read(&mut buf).await?;
+                if n == 0 {
+                    return io::Result::Ok(reader);
+                }
+                s.send(buf[..n].to_vec()).await.ok();
+            }
+        });
+        let write_task = spawn(async move {
+            while let Ok(buf) = r.recv().await {
+                writer.write_all(&buf).await?;
+            }
+            io::Result::Ok(writer)
+        });
+
+        let (mut client_reader, mut client_writer) = client.split();
+        client_writer.write_all(LOREM_IPSUM).await?;
+        let mut buf = vec![0; LOREM_IPSUM.len()];
+        client_reader.read_exact(&mut buf).await?;
+        assert_eq!(buf, LOREM_IPSUM);
+
+        // Dropping the write half keeps the socket registered for the read half.
+        client_writer.get_ref().shutdown(Shutdown::Write)?;
+        drop(client_writer);
+        let reader = read_task.await?;
+        let writer = write_task.await?;
+
+        let server = reader.reunite(writer).unwrap();
+        assert_eq!(
+            server.get_ref().peer_addr()?,
+            client_reader.get_ref().local_addr()?
+        );
+        server.get_ref().shutdown(Shutdown::Write)?;
+        assert_eq!(client_reader.read(&mut buf).await?, 0);
+
+        Ok(())
+    })
+}
+
+#[cfg(unix)]
+#[test]
+fn uds_reunite_mismatch() -> io::Result<()> {
+    let (stream1, stream2) = Async::<UnixStream>::pair()?;
+    let (reader1, writer1) = stream1.split();
+    let (reader2, writer2) = stream2.split();
+
+    let err = reader1.reunite(writer2).unwrap_err();
+    let (reader1, writer2) = (err.0, err.1);
+    assert!(reader1.reunite(writer1).is_ok());
+    assert!(writer2.reunite(reader2).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn tcp_connect_addrs() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+
+        // TEST-NET-1 is never routed, so connecting to it either stalls or fails right away.
+        let blackhole = SocketAddr::from(([192, 0, 2, 1], 80));
+
+        let start = Instant::now();
+        let (stream, _) = future::try_zip(
+            Async::<TcpStream>::connect_addrs(vec![blackhole, addr]),
+            listener.accept(),
+        )
+        .await?;
+        assert!(start.elapsed() < Duration::from_secs(2));
+        assert_eq!(stream.get_ref().peer_addr()?, addr);
+
+        Ok(())
+    })
+}
+
+#[test]
+fn tcp_connect_addrs_errors() -> io::Result<()> {
+    future::block_on(async {
+        let err = Async::<TcpStream>::connect_addrs(Vec::new())
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+
+        // Grab two ports that nobody is listening on.
+        let closed1 = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let closed2 = TcpListener::bind("127.0.0.1:0")?.local_addr()?;
+        let err = Async::<TcpStream>::connect_addrs(vec![closed1, closed2])
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+
+        Ok(())
+    })
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn pidfd_exit() -> io::Result<()> {
+    use async_io::os::linux::PidFd;
+    use std::process::Command;
+
+    future::block_on(async {
+        let mut child = Command::new("sleep").arg("0.1").spawn()?;
+        let pidfd = Async::new(PidFd::open(&child)?)?;
+
+        pidfd.readable().await?;
+        let status = child.try_wait()?.expect("child has not exited");
+        assert!(status.success());
+
+        Ok(())
+    })
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn linux_wait_child() -> io::Result<()> {
+    use std::process::Command;
+
+    future::block_on(async {
+        let mut child = Command::new("sh")
+            .args(["-c", "sleep 0.1; exit 3"])
+            .spawn()?;
+        let status = async_io::os::linux::wait(&mut child).await?;
+        assert_eq!(status.code(), Some(3));
+
+        // The child has been reaped, and waiting again returns the same status.
+        assert_eq!(async_io::os::linux::wait(&mut child).await?.code(), Some(3));
+
+        Ok(())
+    })
+}
+
+// Only epoll and kqueue are known to reject or misreport regular files.
+#[cfg(all(
+    any(
+        target_os = "linux",
+        target_os = "android",
+        target_vendor = "apple",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "dragonfly",
+    ),
+    not(polling_test_poll_backend),
+))]
+#[test]
+fn regular_file_unsupported() -> io::Result<()> {
+    let dir = tempdir()?;
+    let path = dir.path().join("file");
+
+    let file = std::fs::File::create(&path)?;
+    let err = Async::new(file).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+    let dir = std::fs::File::open(dir.path())?;
+    let err = Async::new(dir).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::Unsupported);
+
+    // Pipes and sockets are still pollable.
+    let (reader, writer) = rustix::io::pipe()?;
+    let reader = Async::new(std::fs::File::from(reader))?;
+    let writer = Async::new(std::fs::File::from(writer))?;
+    future::block_on(async {
+        let mut reader = &reader;
+        let mut writer = &writer;
+        writer.write_all(b"hello").await?;
+        let mut buf = [0; 5];
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+        io::Result::Ok(())
+    })?;
+    let (a, _b) = UnixStream::pair()?;
+    Async::new(a)?;
+
+    Ok(())
+}
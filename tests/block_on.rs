@@ -0,0 +1,89 @@
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use async_io::{block_on_timeout, Async, Timer};
+use futures_lite::{future, prelude::*};
+
+#[test]
+fn timeout_pending() {
+    let start = Instant::now();
+    let res = block_on_timeout(future::pending::<()>(), Duration::from_millis(100));
+    let elapsed = start.elapsed();
+
+    assert_eq!(res, None);
+    assert!(elapsed >= Duration::from_millis(100));
+    assert!(elapsed < Duration::from_secs(1));
+}
+
+#[test]
+fn timeout_ready() {
+    let res = block_on_timeout(
+        async {
+            Timer::after(Duration::from_millis(10)).await;
+            7
+        },
+        Duration::from_secs(10),
+    );
+    assert_eq!(res, Some(7));
+}
+
+#[test]
+fn timeout_drops_future() {
+    struct Guard<'a>(&'a mut bool);
+
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            *self.0 = true;
+        }
+    }
+
+    let mut dropped = false;
+    let res = block_on_timeout(
+        async {
+            let _guard = Guard(&mut dropped);
+            future::pending::<()>().await
+        },
+        Duration::from_millis(10),
+    );
+
+    assert_eq!(res, None);
+    assert!(dropped);
+}
+
+#[test]
+fn nested_timer() {
+    let res = async_io::block_on(async {
+        Timer::after(Duration::from_millis(1)).await;
+        let inner = async_io::block_on(async {
+            Timer::after(Duration::from_millis(10)).await;
+            1
+        });
+        Timer::after(Duration::from_millis(1)).await;
+        inner + 1
+    });
+    assert_eq!(res, 2);
+}
+
+#[test]
+fn nested_io() -> io::Result<()> {
+    async_io::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+
+        let writer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            async_io::block_on(server.write_all(b"hello"))
+        });
+
+        // The inner call has to wait on the reactor for the data to arrive.
+        let mut buf = [0; 5];
+        async_io::block_on(client.read_exact(&mut buf))?;
+        assert_eq!(&buf, b"hello");
+
+        writer.join().unwrap()
+    })
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+use std::io;
+use std::net::UdpSocket;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use async_io::Async;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// The highest key a source has been registered with so far.
+static MAX_KEY: AtomicU64 = AtomicU64::new(0);
+
+/// A subscriber that tracks the keys of registered sources in `MAX_KEY`.
+struct KeyTracker;
+
+impl Subscriber for KeyTracker {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        if let (true, Some(key)) = (fields.registered, fields.key) {
+            MAX_KEY.fetch_max(key, Ordering::SeqCst);
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[derive(Default)]
+struct Fields {
+    registered: bool,
+    key: Option<u64>,
+}
+
+impl Visit for Fields {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "key" {
+            self.key = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.registered = format!("{:?}", value) == "registered source";
+        }
+    }
+}
+
+#[test]
+fn register_many_sources() -> io::Result<()> {
+    tracing::subscriber::set_global_default(KeyTracker).unwrap();
+
+    let socket = UdpSocket::bind("127.0.0.1:0")?;
+    let before = async_io::reactor_metrics().sources();
+
+    // Register and remove 100k sources in batches so that freed slots get reused.
+    for _ in 0..10_000 {
+        let batch = (0..10)
+            .map(|_| Async::new(socket.try_clone()?))
+            .collect::<io::Result<Vec<_>>>()?;
+        assert!(async_io::reactor_metrics().sources() >= before + batch.len());
+    }
+
+    // Reusing slots keeps the keys, and so the reactor's storage, bounded by the batch size.
+    assert!(MAX_KEY.load(Ordering::SeqCst) < (before + 10) as u64);
+    assert_eq!(async_io::reactor_metrics().sources(), before);
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use std::future::Future;
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use async_io::{Async, Timer};
+use futures_lite::{future, pin, prelude::*};
+use waker_fn::waker_fn;
+
+/// Runs a future to completion by driving the reactor with `poll_io()` on this thread.
+fn drive<T>(future: impl Future<Output = T>) -> T {
+    pin!(future);
+    let waker = waker_fn(|| {});
+    let cx = &mut Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(t) = future.as_mut().poll(cx) {
+            return t;
+        }
+        async_io::poll_io(Some(Duration::from_secs(1))).unwrap();
+    }
+}
+
+#[test]
+fn disable_driver_thread() -> io::Result<()> {
+    assert!(async_io::disable_driver_thread());
+    assert!(async_io::disable_driver_thread());
+
+    async_io::block_on(async {
+        let start = Instant::now();
+        Timer::after(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+        client.write_all(b"hello").await?;
+        let mut buf = [0; 5];
+        server.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+
+        io::Result::Ok(())
+    })?;
+
+    // No thread named "async-io" has been spawned.
+    #[cfg(target_os = "linux")]
+    for task in std::fs::read_dir("/proc/self/task")? {
+        let comm = std::fs::read_to_string(task?.path().join("comm"))?;
+        assert_ne!(comm.trim(), "async-io");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn poll_io_manually() -> io::Result<()> {
+    async_io::disable_driver_thread();
+
+    drive(async {
+        let start = Instant::now();
+        Timer::after(Duration::from_millis(10)).await;
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let ((mut server, _), mut client) =
+            future::try_zip(listener.accept(), Async::<TcpStream>::connect(addr)).await?;
+        client.write_all(b"hello").await?;
+        let mut buf = [0; 5];
+        server.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"hello");
+
+        Ok(())
+    })
+}
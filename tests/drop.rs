@@ -0,0 +1,36 @@
+#![cfg(unix)]
+
+use std::io;
+use std::os::unix::io::{AsRawFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
+use std::os::unix::net::UnixStream;
+
+use async_io::Async;
+
+/// A descriptor that is closed behind the back of its `Async` wrapper.
+struct Unowned(RawFd);
+
+impl AsRawFd for Unowned {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+#[test]
+fn drop_closed_source() -> io::Result<()> {
+    let before = async_io::reactor_metrics().sources();
+
+    for _ in 0..100 {
+        let (stream, _peer) = UnixStream::pair()?;
+        let fd = stream.into_raw_fd();
+        let source = Async::new(Unowned(fd))?;
+
+        // Closing the descriptor first makes deregistration fail with `EBADF`.
+        drop(unsafe { OwnedFd::from_raw_fd(fd) });
+        drop(source);
+    }
+
+    // The reactor still forgets every source.
+    assert_eq!(async_io::reactor_metrics().sources(), before);
+
+    Ok(())
+}
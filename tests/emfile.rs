@@ -0,0 +1,80 @@
+#![cfg(unix)]
+
+use std::io;
+use std::net::{TcpListener, TcpStream};
+use std::time::{Duration, Instant};
+
+use async_io::{Async, Timer};
+use futures_lite::{future, Stream, StreamExt};
+use rustix::process::{getrlimit, setrlimit, Resource, Rlimit};
+
+/// Polls `incoming` until `deadline` and returns how many `EMFILE` errors it yielded.
+async fn count_errors(
+    incoming: &mut (impl Stream<Item = io::Result<Async<TcpStream>>> + Unpin),
+    deadline: Instant,
+) -> usize {
+    let mut errors = 0;
+    while Instant::now() < deadline {
+        let next = future::or(async { incoming.next().await }, async {
+            Timer::at(deadline).await;
+            None
+        });
+        match next.await {
+            Some(Err(err)) => {
+                assert_eq!(
+                    err.raw_os_error(),
+                    Some(rustix::io::Errno::MFILE.raw_os_error())
+                );
+                errors += 1;
+            }
+            Some(Ok(_)) => panic!("accepted a connection past the descriptor limit"),
+            None => {}
+        }
+    }
+    errors
+}
+
+#[test]
+fn incoming_backs_off_without_fds() -> io::Result<()> {
+    future::block_on(async {
+        let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+        let addr = listener.get_ref().local_addr()?;
+        let _clients = (0..4)
+            .map(|_| TcpStream::connect(addr))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        // Leave no room for the descriptors of accepted connections.
+        let next_fd = {
+            let probe = TcpListener::bind("127.0.0.1:0")?;
+            std::os::unix::io::AsRawFd::as_raw_fd(&probe)
+        };
+        let limit = getrlimit(Resource::Nofile);
+        setrlimit(
+            Resource::Nofile,
+            Rlimit {
+                current: Some(next_fd as u64),
+                maximum: limit.maximum,
+            },
+        )?;
+
+        // Two streams share the listener, and each backs off on its own.
+        let mut incoming1 = listener.incoming();
+        let mut incoming2 = listener.incoming();
+        let deadline = Instant::now() + Duration::from_millis(100);
+        let (errors1, errors2) = future::zip(
+            count_errors(&mut incoming1, deadline),
+            count_errors(&mut incoming2, deadline),
+        )
+        .await;
+        setrlimit(Resource::Nofile, limit)?;
+
+        // The streams keep yielding errors, but not in a tight loop.
+        for errors in [errors1, errors2] {
+            assert!(errors >= 1);
+            assert!(errors <= 15, "{} errors in 100ms", errors);
+        }
+
+        incoming1.next().await.unwrap()?;
+        Ok(())
+    })
+}
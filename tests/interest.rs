@@ -0,0 +1,32 @@
+use std::io;
+use std::net::UdpSocket;
+use std::time::Duration;
+
+use async_io::Async;
+use futures_lite::future;
+
+#[test]
+fn cancelled_interest() -> io::Result<()> {
+    // Only this test drives the reactor, so every event shows up in the metrics below.
+    assert!(async_io::disable_driver_thread());
+
+    let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+    let addr = socket.get_ref().local_addr()?;
+    let sender = UdpSocket::bind("127.0.0.1:0")?;
+
+    // Arm the socket, then give up waiting.
+    assert!(future::block_on(future::poll_once(socket.readable())).is_none());
+
+    // A disarmed socket does not produce events when the peer writes.
+    let before = async_io::reactor_metrics().events();
+    sender.send_to(b"hello", addr)?;
+    async_io::poll_io(Some(Duration::from_millis(100)))?;
+    assert_eq!(async_io::reactor_metrics().events(), before);
+
+    // Waiting again restores interest and observes the pending datagram.
+    let mut buf = [0; 5];
+    let n = async_io::block_on(socket.recv(&mut buf))?;
+    assert_eq!(&buf[..n], b"hello");
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use std::io;
+use std::net::UdpSocket;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use async_io::{Async, Timer};
+use futures_lite::future;
+
+#[test]
+fn reactor_metrics() -> io::Result<()> {
+    let before = async_io::reactor_metrics();
+
+    let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let mut timer = Timer::at(deadline);
+    assert!(future::block_on(future::poll_once(&mut timer)).is_none());
+
+    let metrics = async_io::reactor_metrics();
+    assert_eq!(metrics.sources(), before.sources() + 1);
+    assert_eq!(metrics.timers(), before.timers() + 1);
+    assert_eq!(metrics.next_timer(), Some(deadline));
+
+    // Receiving a datagram takes at least one wait that delivers an event.
+    async_io::block_on(async {
+        let addr = socket.get_ref().local_addr()?;
+        let sender = UdpSocket::bind("127.0.0.1:0")?;
+        let recv = async {
+            let mut buf = [0; 5];
+            socket.recv(&mut buf).await
+        };
+        let send = async {
+            Timer::after(Duration::from_millis(50)).await;
+            sender.send_to(b"hello", addr)
+        };
+        future::try_zip(recv, send).await
+    })?;
+
+    let after = async_io::reactor_metrics();
+    assert!(after.waits() > metrics.waits());
+    assert!(after.events() > metrics.events());
+
+    // While `block_on()` is parked on the reactor, both inserting a timer from another thread and
+    // waking the blocked task from there have to go through a notification.
+    let (s, r) = async_channel::bounded(1);
+    let inserter = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        let mut timer = Timer::after(Duration::from_secs(60));
+        assert!(future::block_on(future::poll_once(&mut timer)).is_none());
+        s.send_blocking(()).unwrap();
+    });
+    async_io::block_on(r.recv()).unwrap();
+    inserter.join().unwrap();
+    assert!(async_io::reactor_metrics().notifications() > after.notifications());
+
+    drop(socket);
+    drop(timer);
+    let last = async_io::reactor_metrics();
+    assert_eq!(last.sources(), before.sources());
+    assert_eq!(last.timers(), before.timers());
+
+    Ok(())
+}
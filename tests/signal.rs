@@ -0,0 +1,107 @@
+#![cfg(unix)]
+
+use std::future::Future;
+use std::io;
+use std::os::unix::net::UnixStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use async_io::{Async, Timer};
+use futures_lite::{future, prelude::*};
+use rustix::process::{getpid, kill_process, Signal};
+
+fn spawn<T: Send + 'static>(
+    f: impl Future<Output = T> + Send + 'static,
+) -> impl Future<Output = T> + Send + 'static {
+    let (s, r) = async_channel::bounded(1);
+
+    thread::spawn(move || {
+        future::block_on(async {
+            s.send(f.await).await.ok();
+        })
+    });
+
+    Box::pin(async move { r.recv().await.unwrap() })
+}
+
+// Signals are delivered to the whole process, so these tests live in their own binary where they
+// can't interrupt blocking calls made by other tests.
+#[test]
+fn interrupted_io() -> io::Result<()> {
+    // Install a handler so that `SIGURG` interrupts the reactor's wait instead of being ignored.
+    let received = Arc::new(AtomicBool::new(false));
+    signal_hook::flag::register(signal_hook::consts::SIGURG, received.clone())?;
+
+    let done = Arc::new(AtomicBool::new(false));
+    let signaller = thread::spawn({
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::SeqCst) {
+                kill_process(getpid(), Signal::Urg).unwrap();
+                thread::sleep(Duration::from_micros(200));
+            }
+        }
+    });
+
+    let res = async_io::block_on(async {
+        let (mut stream1, mut stream2) = Async::<UnixStream>::pair()?;
+        let echo = spawn(async move {
+            let mut buf = [0; 64];
+            loop {
+                let n = stream2.read(&mut buf).await?;
+                if n == 0 {
+                    return io::Result::Ok(());
+                }
+                Timer::after(Duration::from_millis(1)).await;
+                stream2.write_all(&buf[..n]).await?;
+            }
+        });
+
+        let mut buf = [0; 16];
+        for i in 0..200u32 {
+            let start = Instant::now();
+            stream1.write_all(&i.to_le_bytes()).await?;
+            stream1.read_exact(&mut buf[..4]).await?;
+            assert_eq!(buf[..4], i.to_le_bytes());
+            assert!(start.elapsed() >= Duration::from_millis(1));
+        }
+
+        drop(stream1);
+        echo.await
+    });
+
+    done.store(true, Ordering::SeqCst);
+    signaller.join().unwrap();
+    assert!(received.load(Ordering::SeqCst));
+    res
+}
+
+#[test]
+fn interrupted_timer() {
+    // Install a handler so that `SIGURG` interrupts blocking syscalls instead of being ignored.
+    let received = Arc::new(AtomicBool::new(false));
+    signal_hook::flag::register(signal_hook::consts::SIGURG, received.clone()).unwrap();
+
+    let done = Arc::new(AtomicBool::new(false));
+    let signaller = thread::spawn({
+        let done = done.clone();
+        move || {
+            while !done.load(Ordering::SeqCst) {
+                kill_process(getpid(), Signal::Urg).unwrap();
+                thread::sleep(Duration::from_millis(1));
+            }
+        }
+    });
+
+    async_io::block_on(async {
+        let start = Instant::now();
+        Timer::after(Duration::from_millis(500)).await;
+        assert!(start.elapsed() >= Duration::from_millis(500));
+    });
+
+    done.store(true, Ordering::SeqCst);
+    signaller.join().unwrap();
+    assert!(received.load(Ordering::SeqCst));
+}
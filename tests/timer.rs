@@ -4,7 +4,7 @@ use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 
-use async_io::Timer;
+use async_io::{MissedTicks, Timer};
 use futures_lite::{future, FutureExt, StreamExt};
 
 fn spawn<T: Send + 'static>(
@@ -30,6 +30,18 @@ fn smoke() {
     });
 }
 
+#[test]
+fn sub_millisecond_deadline() {
+    // The reactor may wake up early when the backend rounds timeouts to whole milliseconds, but
+    // the timer must not fire until its deadline has actually passed.
+    for _ in 0..20 {
+        let start = Instant::now();
+        let when = future::block_on(Timer::after(Duration::from_micros(2500)));
+        assert!(when >= start + Duration::from_micros(2500));
+        assert!(start.elapsed() >= Duration::from_micros(2500));
+    }
+}
+
 #[test]
 fn interval() {
     future::block_on(async {
@@ -97,3 +109,248 @@ fn set() {
         assert!(start.elapsed() < Duration::from_secs(10));
     });
 }
+
+#[test]
+fn interval_skips_missed_ticks() {
+    future::block_on(async {
+        let period = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut timer = Timer::interval_at(start, period);
+
+        // The first tick fires right away.
+        assert_eq!(timer.next().await, Some(start));
+
+        // Fall behind by a couple of periods.
+        thread::sleep(Duration::from_millis(250));
+
+        // The overdue tick is emitted immediately...
+        let before = Instant::now();
+        assert_eq!(timer.next().await, Some(start + period));
+        assert!(before.elapsed() < Duration::from_millis(50));
+
+        // ...and then the timer resumes its original cadence, skipping the missed tick.
+        assert_eq!(timer.next().await, Some(start + period * 3));
+        assert!(start.elapsed() >= period * 3);
+    });
+}
+
+#[test]
+fn interval_bursts_missed_ticks() {
+    future::block_on(async {
+        let period = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut timer = Timer::interval_at(start, period).with_missed_ticks(MissedTicks::Burst);
+        assert_eq!(timer.next().await, Some(start));
+
+        // Fall behind by a couple of periods.
+        thread::sleep(Duration::from_millis(250));
+
+        // Every missed tick is emitted right away...
+        let before = Instant::now();
+        assert_eq!(timer.next().await, Some(start + period));
+        assert_eq!(timer.next().await, Some(start + period * 2));
+        assert!(before.elapsed() < Duration::from_millis(50));
+
+        // ...until the timer has caught up with its schedule.
+        assert_eq!(timer.next().await, Some(start + period * 3));
+        assert!(start.elapsed() >= period * 3);
+    });
+}
+
+#[test]
+fn interval_delays_after_missed_ticks() {
+    future::block_on(async {
+        let period = Duration::from_millis(100);
+        let start = Instant::now();
+        let mut timer = Timer::interval_at(start, period).with_missed_ticks(MissedTicks::Delay);
+        assert_eq!(timer.next().await, Some(start));
+
+        // Fall behind by a couple of periods.
+        thread::sleep(Duration::from_millis(250));
+
+        // The overdue tick is emitted immediately...
+        let before = Instant::now();
+        assert_eq!(timer.next().await, Some(start + period));
+        assert!(before.elapsed() < Duration::from_millis(50));
+
+        // ...and the schedule is shifted so the next one comes a full period later.
+        let next = timer.next().await.unwrap();
+        assert!(next >= before + period);
+        assert!(Instant::now() >= next);
+    });
+}
+
+#[test]
+fn at_past() {
+    future::block_on(async {
+        let when = Instant::now() - Duration::from_secs(1);
+        assert_eq!(Timer::at(when).await, when);
+    });
+}
+
+#[test]
+fn at_earlier_than_pending() {
+    future::block_on(async {
+        let start = Instant::now();
+
+        // Keep the reactor busy waiting on a distant deadline.
+        let _far = spawn(Timer::at(start + Duration::from_secs(10)));
+        Timer::after(Duration::from_millis(100)).await;
+
+        // A nearer deadline must still wake the reactor on time.
+        Timer::at(Instant::now() + Duration::from_millis(100)).await;
+        assert!(start.elapsed() < Duration::from_secs(5));
+    });
+}
+
+#[test]
+fn set_after_fired() {
+    future::block_on(async {
+        let mut timer = Timer::after(Duration::from_millis(100));
+
+        // Register the timer in the reactor and let it fire without polling it again.
+        assert!(future::poll_once(&mut timer).await.is_none());
+        thread::sleep(Duration::from_millis(200));
+
+        let start = Instant::now();
+        timer.set_after(Duration::from_millis(500));
+        timer.await;
+        assert!(start.elapsed() >= Duration::from_millis(500));
+    });
+}
+
+#[test]
+fn interval_even_spacing() {
+    future::block_on(async {
+        let period = Duration::from_millis(10);
+        let start = Instant::now();
+        let mut timer = Timer::interval_at(start + period, period);
+
+        let mut last = start;
+        for _ in 0..10 {
+            let tick = timer.next().await.unwrap();
+
+            // Ticks are scheduled relative to the original deadline, so they never drift.
+            assert!(tick > last);
+            assert_eq!((tick - start).as_nanos() % period.as_nanos(), 0);
+            last = tick;
+        }
+
+        let elapsed = start.elapsed();
+        assert!(elapsed >= period * 10);
+        assert!(elapsed < period * 10 + Duration::from_millis(500));
+    });
+}
+
+#[test]
+fn set_after_repeatedly() {
+    future::block_on(async {
+        let start = Instant::now();
+        let mut timer = Timer::after(Duration::from_millis(100));
+        assert!(future::poll_once(&mut timer).await.is_none());
+
+        for ms in [200, 300, 400] {
+            timer.set_after(Duration::from_millis(ms));
+        }
+
+        // Only the final deadline is observed.
+        (&mut timer).await;
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        assert!(!timer.will_fire());
+
+        // Resetting a timer that has already fired arms it again.
+        let start = Instant::now();
+        timer.set_after(Duration::from_millis(100));
+        assert!(timer.will_fire());
+        timer.await;
+        assert!(start.elapsed() >= Duration::from_millis(100));
+    });
+}
+
+#[test]
+fn granularity_never_early() {
+    future::block_on(async {
+        for granularity in [1, 7, 20, 50] {
+            let granularity = Duration::from_millis(granularity);
+            let start = Instant::now();
+            Timer::after(Duration::from_millis(30))
+                .with_granularity(granularity)
+                .await;
+            let elapsed = start.elapsed();
+
+            assert!(elapsed >= Duration::from_millis(30));
+            assert!(elapsed < Duration::from_millis(30) + granularity + Duration::from_millis(200));
+        }
+    });
+}
+
+#[test]
+fn granularity_interval() {
+    future::block_on(async {
+        let period = Duration::from_millis(20);
+        let ticks: Vec<Instant> = Timer::interval(period)
+            .with_granularity(Duration::from_millis(15))
+            .take(5)
+            .collect()
+            .await;
+
+        // Ticks stay on the original schedule rather than drifting by the rounding.
+        for pair in ticks.windows(2) {
+            let gap = pair[1] - pair[0];
+            assert_eq!(gap.as_nanos() % period.as_nanos(), 0);
+        }
+    });
+}
+
+#[test]
+fn huge_durations() {
+    let century = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+    for duration in [Duration::MAX, Duration::from_secs(u64::MAX), century] {
+        // None of these overflow into the past.
+        let mut timer = Timer::after(duration);
+        assert_eq!(timer.will_fire(), duration == century);
+        assert!(future::block_on(future::poll_once(&mut timer)).is_none());
+
+        let mut interval = Timer::interval(duration);
+        assert!(future::block_on(future::poll_once(interval.next())).is_none());
+
+        // The reactor keeps processing other timers while these are pending.
+        let mut set = Timer::after(Duration::from_millis(10));
+        set.set_after(duration);
+        let start = Instant::now();
+        async_io::block_on(async {
+            let pending = future::or(timer, set);
+            let pending = future::or(pending, async {
+                interval.next().await;
+                unreachable!()
+            });
+            future::or(pending, Timer::after(Duration::from_millis(100))).await
+        });
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(100) && elapsed < Duration::from_secs(5));
+    }
+}
+
+#[test]
+fn never_then_armed() {
+    future::block_on(async {
+        let mut timer = Timer::never();
+        assert!(!timer.will_fire());
+        assert!(future::poll_once(&mut timer).await.is_none());
+
+        // The same timer can be armed later, and wakes the task that polled it.
+        let start = Instant::now();
+        timer.set_after(Duration::from_millis(100));
+        assert!(timer.will_fire());
+        timer.await;
+        assert!(start.elapsed() >= Duration::from_millis(100));
+
+        // An interval can be armed as well.
+        let mut timer = Timer::never();
+        assert!(future::poll_once(timer.next()).await.is_none());
+        timer.set_interval(Duration::from_millis(10));
+        timer.next().await;
+        timer.next().await;
+    });
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "tokio-io")]
+
+use std::io::{self, IoSlice};
+use std::net::{TcpListener, TcpStream};
+
+use async_io::{Async, Compat};
+use futures_lite::future;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Writes a length-prefixed frame.
+async fn write_frame(io: &mut (impl AsyncWrite + Unpin), frame: &[u8]) -> io::Result<()> {
+    let len = (frame.len() as u32).to_be_bytes();
+    let n = io
+        .write_vectored(&[IoSlice::new(&len), IoSlice::new(frame)])
+        .await?;
+
+    // Write whatever the vectored write didn't get to.
+    let rest: Vec<u8> = len.iter().chain(frame).skip(n).copied().collect();
+    io.write_all(&rest).await
+}
+
+/// Reads a length-prefixed frame, or returns `None` at the end of the stream.
+async fn read_frame(io: &mut (impl AsyncRead + Unpin)) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0; 4];
+    match io.read_exact(&mut len).await {
+        Ok(_) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+    let mut frame = vec![0; u32::from_be_bytes(len) as usize];
+    io.read_exact(&mut frame).await?;
+    Ok(Some(frame))
+}
+
+async fn pair() -> io::Result<(Compat<TcpStream>, Compat<TcpStream>)> {
+    let listener = Async::<TcpListener>::bind(([127, 0, 0, 1], 0))?;
+    let addr = listener.get_ref().local_addr()?;
+    let (client, (server, _)) =
+        future::try_zip(Async::<TcpStream>::connect(addr), listener.accept()).await?;
+    Ok((client.compat(), server.compat()))
+}
+
+#[test]
+fn framed() -> io::Result<()> {
+    future::block_on(async {
+        let (mut client, mut server) = pair().await?;
+        let frames: Vec<Vec<u8>> = vec![b"hello".to_vec(), Vec::new(), vec![7; 100_000]];
+
+        let write = async {
+            for frame in &frames {
+                write_frame(&mut client, frame).await?;
+            }
+            // Shutting down the write half ends the stream for the peer.
+            client.shutdown().await
+        };
+        let read = async {
+            let mut received = Vec::new();
+            while let Some(frame) = read_frame(&mut server).await? {
+                received.push(frame);
+            }
+            Ok(received)
+        };
+        let ((), received) = future::try_zip(write, read).await?;
+        assert_eq!(received, frames);
+
+        // The other direction is still open.
+        server.write_all(b"bye").await?;
+        server.shutdown().await?;
+        let mut buf = Vec::new();
+        client.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"bye");
+
+        Ok(())
+    })
+}
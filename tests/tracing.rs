@@ -0,0 +1,183 @@
+use std::fmt;
+use std::io;
+use std::net::UdpSocket;
+use std::sync::{Mutex, MutexGuard, Once};
+use std::time::Duration;
+
+use async_io::{Async, Timer};
+use futures_lite::{future, StreamExt};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+/// The message and `key` field of every event emitted so far.
+///
+/// Timer events identify the timer with an `id` field, which is recorded as the key.
+static EVENTS: Mutex<Vec<(String, Option<u64>)>> = Mutex::new(Vec::new());
+
+/// A subscriber that records events into `EVENTS`.
+struct Recorder;
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        EVENTS.lock().unwrap().push((fields.message, fields.key));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[derive(Default)]
+struct Fields {
+    message: String,
+    key: Option<u64>,
+}
+
+impl Visit for Fields {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "key" || field.name() == "id" {
+            self.key = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        }
+    }
+}
+
+/// Installs the recorder and returns the number of events recorded so far.
+///
+/// Tests hold the returned guard so that their events don't interleave.
+fn record() -> (MutexGuard<'static, ()>, usize) {
+    static INIT: Once = Once::new();
+    static TEST: Mutex<()> = Mutex::new(());
+
+    INIT.call_once(|| tracing::subscriber::set_global_default(Recorder).unwrap());
+    let guard = TEST.lock().unwrap_or_else(|err| err.into_inner());
+    (guard, EVENTS.lock().unwrap().len())
+}
+
+/// Returns how many events with the given message were recorded for `key` since `start`.
+fn count(start: usize, message: &str, key: u64) -> usize {
+    EVENTS.lock().unwrap()[start..]
+        .iter()
+        .filter(|(msg, k)| msg == message && *k == Some(key))
+        .count()
+}
+
+#[test]
+fn reactor_events() -> io::Result<()> {
+    let (_guard, start) = record();
+
+    let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+    let addr = socket.get_ref().local_addr()?;
+    async_io::block_on(async {
+        let mut buf = [0; 5];
+        let (n, _) = future::try_zip(socket.recv(&mut buf), async {
+            UdpSocket::bind("127.0.0.1:0")?.send_to(b"hello", addr)
+        })
+        .await?;
+        assert_eq!(&buf[..n], b"hello");
+        io::Result::Ok(())
+    })?;
+    drop(socket);
+
+    // Find the key the socket was registered with.
+    let events = EVENTS.lock().unwrap()[start..].to_vec();
+    let key = events
+        .iter()
+        .find(|(msg, _)| msg == "registered source")
+        .and_then(|(_, key)| *key)
+        .expect("no registration event");
+
+    // The socket's lifecycle shows up in order.
+    let mut expected = [
+        "updating interest",
+        "received I/O event",
+        "deregistering source",
+    ]
+    .iter();
+    let mut next = expected.next();
+    for (msg, k) in &events {
+        if Some(&msg.as_str()) == next && *k == Some(key) {
+            next = expected.next();
+        }
+    }
+    assert_eq!(next, None, "missing events in {:?}", events);
+
+    // The reactor's waits are reported as well.
+    assert!(events
+        .iter()
+        .any(|(msg, _)| msg == "waiting for I/O events"));
+    assert!(events
+        .iter()
+        .any(|(msg, _)| msg == "done waiting for I/O events"));
+
+    Ok(())
+}
+
+#[test]
+fn interest_updated_once() -> io::Result<()> {
+    let (_guard, start) = record();
+
+    let socket = Async::<UdpSocket>::bind(([127, 0, 0, 1], 0))?;
+    let mut a = socket.readable();
+    let mut b = socket.readable();
+
+    // Only the first waiter changes the registered interest.
+    assert!(future::block_on(future::poll_once(&mut a)).is_none());
+    assert!(future::block_on(future::poll_once(&mut b)).is_none());
+    assert!(future::block_on(future::poll_once(&mut a)).is_none());
+    assert!(future::block_on(future::poll_once(socket.readable())).is_none());
+
+    let key = EVENTS.lock().unwrap()[start..]
+        .iter()
+        .find(|(msg, _)| msg == "registered source")
+        .and_then(|(_, key)| *key)
+        .expect("no registration event");
+    assert_eq!(count(start, "updating interest", key), 1);
+
+    // Interest is only updated again once the last waiter is gone.
+    drop(a);
+    assert_eq!(count(start, "updating interest", key), 1);
+    drop(b);
+    assert_eq!(count(start, "updating interest", key), 2);
+
+    Ok(())
+}
+
+#[test]
+fn interval_keeps_timer_id() {
+    let (_guard, start) = record();
+
+    let mut timer = Timer::interval(Duration::from_millis(1));
+    for _ in 0..3 {
+        future::block_on(timer.next());
+    }
+
+    // Every tick fires the same entry in the reactor's timer queue.
+    let ids: Vec<u64> = EVENTS.lock().unwrap()[start..]
+        .iter()
+        .filter(|(msg, _)| msg == "timer fired")
+        .filter_map(|(_, id)| *id)
+        .collect();
+    assert!(ids.len() >= 3, "missing events: {:?}", ids);
+    assert!(ids.iter().all(|id| *id == ids[0]), "new IDs: {:?}", ids);
+}